@@ -0,0 +1,328 @@
+use crate::matrix::Matrix;
+use crate::EditOperation;
+
+/// Last operation of an alignment, used to index cells of the Gotoh matrix
+const MATCH: usize = 0;
+const DELETE: usize = 1;
+const INSERT: usize = 2;
+
+/// Costs used by [`affine_alignment`]
+///
+/// A gap of length `n`, i.e. a run of `n` consecutive insertions or deletions,
+/// costs `gap_open + n * gap_extend`. With a large `gap_open` one long gap is
+/// cheaper than several short ones.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct AffineCosts {
+    /// Cost of replacing an element with a different one
+    pub mismatch: usize,
+    /// Cost paid once for every gap
+    pub gap_open: usize,
+    /// Cost paid for every element of a gap
+    pub gap_extend: usize,
+}
+
+impl AffineCosts {
+    /// Create a new instance of AffineCosts
+    ///
+    /// # Arguments
+    /// * `mismatch` - Cost of replacing an element with a different one
+    /// * `gap_open` - Cost paid once for every gap
+    /// * `gap_extend` - Cost paid for every element of a gap
+    pub fn new(mismatch: usize, gap_open: usize, gap_extend: usize) -> Self {
+        AffineCosts {
+            mismatch,
+            gap_open,
+            gap_extend,
+        }
+    }
+}
+
+/// Result of aligning two words with affine gap costs
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AffineAlignment {
+    /// Total cost of the alignment
+    pub cost: usize,
+    /// Operations ordered from the beginning of both words to their ends
+    pub operations: Vec<EditOperation>,
+}
+
+/// Align two words with affine gap costs
+///
+/// Uses Gotoh's algorithm: three matrices hold the lowest cost of aligning
+/// prefixes of the words that ends with a match or substitution, a deletion,
+/// and an insertion respectively, so opening a gap can be charged separately
+/// from extending it.
+///
+/// # Arguments
+/// * `first_word` - First word
+/// * `second_word` - Second word
+/// * `costs` - Costs of substitutions and gaps
+///
+/// # Examples
+/// ```
+/// use edit_dist::{affine_alignment, AffineCosts, EditOperation};
+/// let costs = AffineCosts::new(1, 3, 1);
+/// let alignment = affine_alignment("abcd".chars(), "abxxcd".chars(), &costs);
+/// assert_eq!(alignment.cost, 5);
+/// assert_eq!(alignment.operations[2], EditOperation::Insert { target: 2 });
+/// assert_eq!(alignment.operations[3], EditOperation::Insert { target: 3 });
+/// ```
+pub fn affine_alignment<T: PartialEq>(
+    first_word: impl IntoIterator<Item = T>,
+    second_word: impl IntoIterator<Item = T>,
+    costs: &AffineCosts,
+) -> AffineAlignment {
+    let first_word: Vec<T> = first_word.into_iter().collect();
+    let second_word: Vec<T> = second_word.into_iter().collect();
+
+    let matrix = gotoh_matrix(&first_word, &second_word, costs);
+    let (y, x) = (second_word.len(), first_word.len());
+    let state = cheapest_at(&matrix, y, x, [0, 0, 0]);
+
+    AffineAlignment {
+        cost: matrix[(y, x)][state],
+        operations: traceback(&matrix, &first_word, &second_word, costs, state),
+    }
+}
+
+/// Fill the Gotoh matrix, every cell holds costs indexed by the last operation
+///
+/// Columns follow the first word and rows follow the second word.
+///
+/// # Arguments
+/// * `first_word` - First word
+/// * `second_word` - Second word
+/// * `costs` - Costs of substitutions and gaps
+fn gotoh_matrix<T: PartialEq>(
+    first_word: &[T],
+    second_word: &[T],
+    costs: &AffineCosts,
+) -> Matrix<[usize; 3]> {
+    let open = costs.gap_open.saturating_add(costs.gap_extend);
+    let extend = costs.gap_extend;
+
+    let mut matrix = Matrix::new(first_word.len() + 1, second_word.len() + 1);
+    matrix[(0, 0)] = [0, usize::MAX, usize::MAX];
+    for x in 1..matrix.width() {
+        let previous: [usize; 3] = matrix[(0, x - 1)];
+        matrix[(0, x)] = [usize::MAX, gap(previous, DELETE, open, extend), usize::MAX];
+    }
+    for y in 1..matrix.height() {
+        let previous: [usize; 3] = matrix[(y - 1, 0)];
+        matrix[(y, 0)] = [usize::MAX, usize::MAX, gap(previous, INSERT, open, extend)];
+    }
+
+    for y in 1..matrix.height() {
+        for x in 1..matrix.width() {
+            let diagonal = matrix[(y - 1, x - 1)];
+            let the_same_letter = first_word[x - 1] == second_word[y - 1];
+            let cost = if the_same_letter { 0 } else { costs.mismatch };
+
+            matrix[(y, x)] = [
+                diagonal[cheapest(diagonal, [0, 0, 0])].saturating_add(cost),
+                gap(matrix[(y, x - 1)], DELETE, open, extend),
+                gap(matrix[(y - 1, x)], INSERT, open, extend),
+            ];
+        }
+    }
+
+    matrix
+}
+
+/// Get the lowest cost of a gap of given kind reaching the next cell
+///
+/// # Arguments
+/// * `previous` - Costs of the cell the gap grows from
+/// * `kind` - Either `DELETE` or `INSERT`
+/// * `open` - Cost of opening a gap, including its first element
+/// * `extend` - Cost of extending a gap by one element
+fn gap(previous: [usize; 3], kind: usize, open: usize, extend: usize) -> usize {
+    let additions = gap_additions(kind, open, extend);
+    let state = cheapest(previous, additions);
+    previous[state].saturating_add(additions[state])
+}
+
+/// Get the costs of growing a gap of given kind, indexed by the previous operation
+fn gap_additions(kind: usize, open: usize, extend: usize) -> [usize; 3] {
+    let mut additions = [open; 3];
+    additions[kind] = extend;
+    additions
+}
+
+/// Get the last operation with the lowest cost after adding given costs
+///
+/// Ties are resolved in favour of a match, then a deletion.
+fn cheapest(costs: [usize; 3], additions: [usize; 3]) -> usize {
+    let total = |state: usize| costs[state].saturating_add(additions[state]);
+    [MATCH, DELETE, INSERT]
+        .iter()
+        .copied()
+        .min_by_key(|&state| total(state))
+        .unwrap_or(MATCH)
+}
+
+/// Get the last operation with the lowest cost of reaching a cell after adding given costs
+///
+/// Cells of the first row can only be reached by a deletion and cells of the
+/// first column only by an insertion, even when saturated costs make all
+/// operations look equally expensive.
+///
+/// # Arguments
+/// * `matrix` - Matrix filled by `gotoh_matrix`
+/// * `y` - Row of the cell
+/// * `x` - Column of the cell
+/// * `additions` - Costs added to the cell's costs, indexed by the operation
+fn cheapest_at(matrix: &Matrix<[usize; 3]>, y: usize, x: usize, additions: [usize; 3]) -> usize {
+    if y == 0 && x > 0 {
+        DELETE
+    } else if x == 0 && y > 0 {
+        INSERT
+    } else {
+        cheapest(matrix[(y, x)], additions)
+    }
+}
+
+/// Recover the operations by walking back through the Gotoh matrix
+///
+/// # Arguments
+/// * `matrix` - Matrix filled by `gotoh_matrix`
+/// * `first_word` - First word
+/// * `second_word` - Second word
+/// * `costs` - Costs of substitutions and gaps
+/// * `state` - Last operation of the alignment
+fn traceback<T: PartialEq>(
+    matrix: &Matrix<[usize; 3]>,
+    first_word: &[T],
+    second_word: &[T],
+    costs: &AffineCosts,
+    mut state: usize,
+) -> Vec<EditOperation> {
+    let open = costs.gap_open.saturating_add(costs.gap_extend);
+    let extend = costs.gap_extend;
+
+    let mut operations = Vec::with_capacity(first_word.len().max(second_word.len()));
+    let (mut y, mut x) = (second_word.len(), first_word.len());
+
+    while x > 0 || y > 0 {
+        match state {
+            MATCH => {
+                let (source, target) = (x - 1, y - 1);
+                operations.push(if first_word[source] == second_word[target] {
+                    EditOperation::Match { source, target }
+                } else {
+                    EditOperation::Substitute { source, target }
+                });
+                y -= 1;
+                x -= 1;
+                state = cheapest_at(matrix, y, x, [0, 0, 0]);
+            }
+            DELETE => {
+                operations.push(EditOperation::Delete { source: x - 1 });
+                x -= 1;
+                state = cheapest_at(matrix, y, x, gap_additions(DELETE, open, extend));
+            }
+            _ => {
+                operations.push(EditOperation::Insert { target: y - 1 });
+                y -= 1;
+                state = cheapest_at(matrix, y, x, gap_additions(INSERT, open, extend));
+            }
+        }
+    }
+
+    operations.reverse();
+    operations
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::levenshtein;
+
+    /// Calculate the cost of an edit script from scratch
+    fn script_cost(operations: &[EditOperation], costs: &AffineCosts) -> usize {
+        let mut total = 0;
+        let mut previous: Option<&EditOperation> = None;
+        for operation in operations {
+            total += match (previous, operation) {
+                (_, EditOperation::Match { .. }) => 0,
+                (_, EditOperation::Substitute { .. }) => costs.mismatch,
+                (Some(EditOperation::Delete { .. }), EditOperation::Delete { .. })
+                | (Some(EditOperation::Insert { .. }), EditOperation::Insert { .. }) => {
+                    costs.gap_extend
+                }
+                _ => costs.gap_open + costs.gap_extend,
+            };
+            previous = Some(operation);
+        }
+        total
+    }
+
+    #[test]
+    fn affine_test_without_gap_open_is_levenshtein() {
+        let costs = AffineCosts::new(1, 0, 1);
+        let words = ["kitten", "sitting", "", "saturday", "sunday", "abc"];
+        for first in words.iter() {
+            for second in words.iter() {
+                let alignment = affine_alignment(first.chars(), second.chars(), &costs);
+                assert_eq!(alignment.cost, levenshtein(first.chars(), second.chars()));
+                assert_eq!(script_cost(&alignment.operations, &costs), alignment.cost);
+            }
+        }
+    }
+
+    #[test]
+    fn affine_test_contiguous_gap() {
+        let costs = AffineCosts::new(2, 4, 1);
+        let alignment = affine_alignment("let x = 1;".bytes(), "let mut x = 1;".bytes(), &costs);
+        assert_eq!(alignment.cost, 8);
+        let inserted: Vec<usize> = alignment
+            .operations
+            .iter()
+            .filter_map(|operation| match operation {
+                EditOperation::Insert { target } => Some(*target),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(inserted.len(), 4);
+        assert!(inserted.windows(2).all(|pair| pair[1] == pair[0] + 1));
+    }
+
+    #[test]
+    fn affine_test_saturated_gap_costs() {
+        let costs = AffineCosts::new(1, usize::MAX, 1);
+
+        let alignment = affine_alignment("ab".chars(), "".chars(), &costs);
+        assert_eq!(alignment.cost, usize::MAX);
+        assert_eq!(
+            alignment.operations,
+            vec![
+                EditOperation::Delete { source: 0 },
+                EditOperation::Delete { source: 1 },
+            ]
+        );
+
+        let alignment = affine_alignment("".chars(), "ab".chars(), &costs);
+        assert_eq!(
+            alignment.operations,
+            vec![
+                EditOperation::Insert { target: 0 },
+                EditOperation::Insert { target: 1 },
+            ]
+        );
+
+        let alignment = affine_alignment("abc".chars(), "b".chars(), &costs);
+        assert_eq!(alignment.operations.len(), 3);
+    }
+
+    #[test]
+    fn affine_test_script_cost() {
+        let costs = AffineCosts::new(3, 5, 2);
+        let words = ["ACGTTGCA", "AGTTTGA", "TTT", "", "ACGACGACG"];
+        for first in words.iter() {
+            for second in words.iter() {
+                let alignment = affine_alignment(first.bytes(), second.bytes(), &costs);
+                assert_eq!(script_cost(&alignment.operations, &costs), alignment.cost);
+            }
+        }
+    }
+}
@@ -0,0 +1,201 @@
+use super::SubstitutionScore;
+use crate::matrix::Matrix;
+use crate::EditOperation;
+
+/// Result of a score-maximizing global alignment of two words
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GlobalAlignment {
+    /// Total score of the alignment
+    pub score: i32,
+    /// Operations ordered from the beginning of both words to their ends
+    pub operations: Vec<EditOperation>,
+}
+
+/// Align two words globally, maximizing the alignment score
+///
+/// Uses the Needleman-Wunsch algorithm. Every aligned pair of elements
+/// contributes its substitution score and every inserted or deleted element
+/// contributes `gap_score`.
+///
+/// # Arguments
+/// * `first_word` - First word
+/// * `second_word` - Second word
+/// * `scoring` - Scores of aligned pairs of elements, e.g. a [`SubstitutionMatrix`](crate::SubstitutionMatrix)
+/// * `gap_score` - Score of a single inserted or deleted element, usually negative
+///
+/// # Examples
+/// ```
+/// use edit_dist::{needleman_wunsch, SubstitutionMatrix};
+/// let blosum62 = SubstitutionMatrix::blosum62();
+/// let alignment = needleman_wunsch("HEAGAWGHEE".bytes(), "PAWHEAE".bytes(), &blosum62, -8);
+/// assert_eq!(alignment.score, -8);
+/// assert_eq!(alignment.operations.len(), 10);
+/// ```
+pub fn needleman_wunsch<T: PartialEq, S: SubstitutionScore<T>>(
+    first_word: impl IntoIterator<Item = T>,
+    second_word: impl IntoIterator<Item = T>,
+    scoring: &S,
+    gap_score: i32,
+) -> GlobalAlignment {
+    let first_word: Vec<T> = first_word.into_iter().collect();
+    let second_word: Vec<T> = second_word.into_iter().collect();
+
+    let mut matrix = Matrix::<i32>::new(first_word.len() + 1, second_word.len() + 1);
+    for y in 1..matrix.height() {
+        matrix[(y, 0)] = matrix[(y - 1, 0)] + gap_score;
+    }
+    for x in 1..matrix.width() {
+        matrix[(0, x)] = matrix[(0, x - 1)] + gap_score;
+    }
+
+    for y in 1..matrix.height() {
+        for x in 1..matrix.width() {
+            let substitution =
+                matrix[(y - 1, x - 1)] + scoring.score(&first_word[x - 1], &second_word[y - 1]);
+            let insertion = matrix[(y - 1, x)] + gap_score;
+            let deletion = matrix[(y, x - 1)] + gap_score;
+
+            matrix[(y, x)] = substitution.max(insertion).max(deletion);
+        }
+    }
+
+    GlobalAlignment {
+        score: matrix[(matrix.height() - 1, matrix.width() - 1)],
+        operations: traceback(&matrix, &first_word, &second_word, scoring, gap_score),
+    }
+}
+
+/// Recover the operations by walking back through the score matrix
+///
+/// # Arguments
+/// * `matrix` - Score matrix filled by `needleman_wunsch`
+/// * `first_word` - First word
+/// * `second_word` - Second word
+/// * `scoring` - Scores of aligned pairs of elements
+/// * `gap_score` - Score of a single inserted or deleted element
+fn traceback<T: PartialEq, S: SubstitutionScore<T>>(
+    matrix: &Matrix<i32>,
+    first_word: &[T],
+    second_word: &[T],
+    scoring: &S,
+    gap_score: i32,
+) -> Vec<EditOperation> {
+    let mut operations = Vec::with_capacity(first_word.len().max(second_word.len()));
+    let (mut y, mut x) = (second_word.len(), first_word.len());
+
+    while x > 0 || y > 0 {
+        let current = matrix[(y, x)];
+
+        if x > 0 && y > 0 {
+            let (source, target) = (x - 1, y - 1);
+            let score = scoring.score(&first_word[source], &second_word[target]);
+
+            if matrix[(y - 1, x - 1)] + score == current {
+                operations.push(if first_word[source] == second_word[target] {
+                    EditOperation::Match { source, target }
+                } else {
+                    EditOperation::Substitute { source, target }
+                });
+                y -= 1;
+                x -= 1;
+                continue;
+            }
+        }
+
+        if x > 0 && matrix[(y, x - 1)] + gap_score == current {
+            operations.push(EditOperation::Delete { source: x - 1 });
+            x -= 1;
+        } else {
+            operations.push(EditOperation::Insert { target: y - 1 });
+            y -= 1;
+        }
+    }
+
+    operations.reverse();
+    operations
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::levenshtein;
+
+    /// Score 1 for equal elements and -1 for different ones
+    struct Identity;
+
+    impl SubstitutionScore<char> for Identity {
+        fn score(&self, first: &char, second: &char) -> i32 {
+            if first == second {
+                1
+            } else {
+                -1
+            }
+        }
+    }
+
+    /// Recalculate the score of an alignment from its operations
+    fn script_score(
+        operations: &[EditOperation],
+        first_word: &[char],
+        second_word: &[char],
+        gap_score: i32,
+    ) -> i32 {
+        operations
+            .iter()
+            .map(|operation| match *operation {
+                EditOperation::Match { source, target }
+                | EditOperation::Substitute { source, target } => {
+                    Identity.score(&first_word[source], &second_word[target])
+                }
+                _ => gap_score,
+            })
+            .sum()
+    }
+
+    #[test]
+    fn needleman_wunsch_test_classic() {
+        let alignment = needleman_wunsch("GATTACA".chars(), "GCATGCU".chars(), &Identity, -1);
+        assert_eq!(alignment.score, 0);
+    }
+
+    #[test]
+    fn needleman_wunsch_test_script_score() {
+        let words = ["GATTACA", "GCATGCU", "", "ACGT", "TTTTGGGG"];
+        for first in words.iter() {
+            for second in words.iter() {
+                let first: Vec<char> = first.chars().collect();
+                let second: Vec<char> = second.chars().collect();
+                for gap_score in [-1, -2, -5].iter() {
+                    let alignment = needleman_wunsch(
+                        first.iter().copied(),
+                        second.iter().copied(),
+                        &Identity,
+                        *gap_score,
+                    );
+                    let score = script_score(&alignment.operations, &first, &second, *gap_score);
+                    assert_eq!(score, alignment.score);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn needleman_wunsch_test_levenshtein_scoring() {
+        /// Negated unit costs, so the best score is minus the Levenshtein distance
+        struct Unit;
+
+        impl SubstitutionScore<char> for Unit {
+            fn score(&self, first: &char, second: &char) -> i32 {
+                if first == second {
+                    0
+                } else {
+                    -1
+                }
+            }
+        }
+
+        let alignment = needleman_wunsch("sitting".chars(), "kitten".chars(), &Unit, -1);
+        let dist = levenshtein("sitting".chars(), "kitten".chars());
+        assert_eq!(alignment.score, -(dist as i32));
+    }
+}
@@ -0,0 +1,314 @@
+use std::ops::Range;
+
+use super::SubstitutionScore;
+use crate::matrix::Matrix;
+use crate::EditOperation;
+
+/// Result of a local alignment: the best-scoring pair of fragments of two words
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LocalAlignment {
+    /// Total score of the alignment
+    pub score: i32,
+    /// Aligned fragment of the first word
+    pub first_range: Range<usize>,
+    /// Aligned fragment of the second word
+    pub second_range: Range<usize>,
+    /// Operations ordered from the beginning of both fragments to their ends
+    pub operations: Vec<EditOperation>,
+}
+
+/// Find the best-scoring local alignment of two words
+///
+/// Uses the Smith-Waterman algorithm: scores never drop below zero, so an
+/// alignment may start and end anywhere and the surroundings of the aligned
+/// fragments are not penalized. Returns `None` if no pair of fragments has a
+/// positive score.
+///
+/// # Arguments
+/// * `first_word` - First word
+/// * `second_word` - Second word
+/// * `scoring` - Scores of aligned pairs of elements, e.g. a [`SubstitutionMatrix`](crate::SubstitutionMatrix)
+/// * `gap_score` - Score of a single inserted or deleted element, usually negative
+///
+/// # Examples
+/// ```
+/// use edit_dist::{smith_waterman, SubstitutionMatrix};
+/// let blosum62 = SubstitutionMatrix::blosum62();
+/// let first = "PPPPHEAGAWGHEEPPPP";
+/// let second = "WWWWHEAGAWGHEEWWWW";
+/// let alignment = smith_waterman(first.bytes(), second.bytes(), &blosum62, -4).unwrap();
+/// assert_eq!(&first[alignment.first_range], "HEAGAWGHEE");
+/// assert_eq!(&second[alignment.second_range], "HEAGAWGHEE");
+/// ```
+pub fn smith_waterman<T: PartialEq, S: SubstitutionScore<T>>(
+    first_word: impl IntoIterator<Item = T>,
+    second_word: impl IntoIterator<Item = T>,
+    scoring: &S,
+    gap_score: i32,
+) -> Option<LocalAlignment> {
+    let first_word: Vec<T> = first_word.into_iter().collect();
+    let second_word: Vec<T> = second_word.into_iter().collect();
+
+    let first_used = vec![false; first_word.len()];
+    let second_used = vec![false; second_word.len()];
+    best_alignment(
+        &first_word,
+        &second_word,
+        scoring,
+        gap_score,
+        &first_used,
+        &second_used,
+    )
+}
+
+/// Find up to `count` best-scoring local alignments which do not overlap
+///
+/// Alignments are found one after another, each one being the best among
+/// those which do not share any element of either word with the ones found
+/// before. Results are sorted by score in descending order.
+///
+/// # Arguments
+/// * `first_word` - First word
+/// * `second_word` - Second word
+/// * `scoring` - Scores of aligned pairs of elements
+/// * `gap_score` - Score of a single inserted or deleted element, usually negative
+/// * `count` - Maximal number of returned alignments
+///
+/// # Examples
+/// ```
+/// use edit_dist::{smith_waterman_top, SubstitutionScore};
+///
+/// struct Identity;
+///
+/// impl SubstitutionScore<char> for Identity {
+///     fn score(&self, first: &char, second: &char) -> i32 {
+///         if first == second { 2 } else { -1 }
+///     }
+/// }
+///
+/// let first = "one shared fragment, then another fragment";
+/// let second = "another fragment ... shared fragment";
+/// let hits = smith_waterman_top(first.chars(), second.chars(), &Identity, -2, 2);
+/// assert_eq!(hits.len(), 2);
+/// assert_eq!(&second[hits[0].second_range.clone()], "another fragment");
+/// assert_eq!(&second[hits[1].second_range.clone()], " shared fragment");
+/// ```
+pub fn smith_waterman_top<T: PartialEq, S: SubstitutionScore<T>>(
+    first_word: impl IntoIterator<Item = T>,
+    second_word: impl IntoIterator<Item = T>,
+    scoring: &S,
+    gap_score: i32,
+    count: usize,
+) -> Vec<LocalAlignment> {
+    let first_word: Vec<T> = first_word.into_iter().collect();
+    let second_word: Vec<T> = second_word.into_iter().collect();
+
+    let mut first_used = vec![false; first_word.len()];
+    let mut second_used = vec![false; second_word.len()];
+    let mut alignments = Vec::with_capacity(count);
+
+    while alignments.len() < count {
+        let alignment = match best_alignment(
+            &first_word,
+            &second_word,
+            scoring,
+            gap_score,
+            &first_used,
+            &second_used,
+        ) {
+            Some(alignment) => alignment,
+            None => break,
+        };
+
+        for used in &mut first_used[alignment.first_range.clone()] {
+            *used = true;
+        }
+        for used in &mut second_used[alignment.second_range.clone()] {
+            *used = true;
+        }
+        alignments.push(alignment);
+    }
+
+    alignments
+}
+
+/// Find the best-scoring local alignment which does not use any of the marked elements
+///
+/// # Arguments
+/// * `first_word` - First word
+/// * `second_word` - Second word
+/// * `scoring` - Scores of aligned pairs of elements
+/// * `gap_score` - Score of a single inserted or deleted element
+/// * `first_used` - Elements of the first word the alignment must not use
+/// * `second_used` - Elements of the second word the alignment must not use
+fn best_alignment<T: PartialEq, S: SubstitutionScore<T>>(
+    first_word: &[T],
+    second_word: &[T],
+    scoring: &S,
+    gap_score: i32,
+    first_used: &[bool],
+    second_used: &[bool],
+) -> Option<LocalAlignment> {
+    // Cells in rows or columns of used elements stay zero, so no alignment
+    // can pass through them
+    let mut matrix = Matrix::<i32>::new(first_word.len() + 1, second_word.len() + 1);
+    let mut best = (0, 0, 0);
+
+    for y in 1..matrix.height() {
+        if second_used[y - 1] {
+            continue;
+        }
+
+        for x in 1..matrix.width() {
+            if first_used[x - 1] {
+                continue;
+            }
+
+            let substitution =
+                matrix[(y - 1, x - 1)] + scoring.score(&first_word[x - 1], &second_word[y - 1]);
+            let insertion = matrix[(y - 1, x)] + gap_score;
+            let deletion = matrix[(y, x - 1)] + gap_score;
+
+            let score = substitution.max(insertion).max(deletion).max(0);
+            matrix[(y, x)] = score;
+            if score > best.0 {
+                best = (score, y, x);
+            }
+        }
+    }
+
+    let (score, y, x) = best;
+    if score == 0 {
+        return None;
+    }
+
+    let (operations, y_start, x_start) =
+        traceback(&matrix, first_word, second_word, scoring, gap_score, y, x);
+    Some(LocalAlignment {
+        score,
+        first_range: x_start..x,
+        second_range: y_start..y,
+        operations,
+    })
+}
+
+/// Recover the operations by walking back from the end of an alignment until the score drops to zero
+///
+/// Returns the operations along with the row and the column the alignment starts at.
+///
+/// # Arguments
+/// * `matrix` - Score matrix filled by `best_alignment`
+/// * `first_word` - First word
+/// * `second_word` - Second word
+/// * `scoring` - Scores of aligned pairs of elements
+/// * `gap_score` - Score of a single inserted or deleted element
+/// * `y` - Row the alignment ends at
+/// * `x` - Column the alignment ends at
+fn traceback<T: PartialEq, S: SubstitutionScore<T>>(
+    matrix: &Matrix<i32>,
+    first_word: &[T],
+    second_word: &[T],
+    scoring: &S,
+    gap_score: i32,
+    mut y: usize,
+    mut x: usize,
+) -> (Vec<EditOperation>, usize, usize) {
+    let mut operations = Vec::new();
+
+    while matrix[(y, x)] > 0 {
+        let current = matrix[(y, x)];
+        let (source, target) = (x - 1, y - 1);
+        let score = scoring.score(&first_word[source], &second_word[target]);
+
+        if matrix[(y - 1, x - 1)] + score == current {
+            operations.push(if first_word[source] == second_word[target] {
+                EditOperation::Match { source, target }
+            } else {
+                EditOperation::Substitute { source, target }
+            });
+            y -= 1;
+            x -= 1;
+        } else if matrix[(y, x - 1)] + gap_score == current {
+            operations.push(EditOperation::Delete { source });
+            x -= 1;
+        } else {
+            operations.push(EditOperation::Insert { target });
+            y -= 1;
+        }
+    }
+
+    operations.reverse();
+    (operations, y, x)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Score `matched` for equal elements and `mismatched` for different ones
+    struct Identity {
+        matched: i32,
+        mismatched: i32,
+    }
+
+    impl SubstitutionScore<char> for Identity {
+        fn score(&self, first: &char, second: &char) -> i32 {
+            if first == second {
+                self.matched
+            } else {
+                self.mismatched
+            }
+        }
+    }
+
+    #[test]
+    fn smith_waterman_test_classic() {
+        let scoring = Identity {
+            matched: 3,
+            mismatched: -3,
+        };
+        let first = "TGTTACGG";
+        let second = "GGTTGACTA";
+        let alignment = smith_waterman(first.chars(), second.chars(), &scoring, -2).unwrap();
+
+        assert_eq!(alignment.score, 13);
+        assert_eq!(&first[alignment.first_range], "GTTAC");
+        assert_eq!(&second[alignment.second_range], "GTTGAC");
+        assert_eq!(alignment.operations.len(), 6);
+        assert_eq!(alignment.operations[3], EditOperation::Insert { target: 4 });
+    }
+
+    #[test]
+    fn smith_waterman_test_no_similarity() {
+        let scoring = Identity {
+            matched: 1,
+            mismatched: -1,
+        };
+        assert_eq!(
+            smith_waterman("abc".chars(), "xyz".chars(), &scoring, -1),
+            None
+        );
+        assert_eq!(
+            smith_waterman("".chars(), "xyz".chars(), &scoring, -1),
+            None
+        );
+        assert!(smith_waterman_top("abc".chars(), "xyz".chars(), &scoring, -1, 3).is_empty());
+    }
+
+    #[test]
+    fn smith_waterman_test_top_hits_do_not_overlap() {
+        let scoring = Identity {
+            matched: 2,
+            mismatched: -3,
+        };
+        let first = "abcdefgh--abcdefgh----xyz";
+        let second = "abcdefgh....xyz";
+        let hits = smith_waterman_top(first.chars(), second.chars(), &scoring, -2, 5);
+
+        assert_eq!(hits.len(), 2);
+        assert_eq!(&first[hits[0].first_range.clone()], "abcdefgh");
+        assert_eq!(&first[hits[1].first_range.clone()], "xyz");
+        assert!(hits[0].score >= hits[1].score);
+        assert!(hits[0].second_range.end <= hits[1].second_range.start);
+    }
+}
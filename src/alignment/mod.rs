@@ -0,0 +1,9 @@
+mod affine;
+mod global;
+mod local;
+mod substitution;
+
+pub use affine::{affine_alignment, AffineAlignment, AffineCosts};
+pub use global::{needleman_wunsch, GlobalAlignment};
+pub use local::{smith_waterman, smith_waterman_top, LocalAlignment};
+pub use substitution::{ParseMatrixError, SubstitutionMatrix, SubstitutionScore};
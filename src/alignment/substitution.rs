@@ -0,0 +1,353 @@
+use std::error::Error;
+use std::fmt;
+
+/// BLOSUM62 in the NCBI text format
+const BLOSUM62: &str = "\
+#  Matrix made by matblas from blosum62.iij
+#  * column uses minimum score
+#  BLOSUM Clustered Scoring Matrix in 1/2 Bit Units
+#  Blocks Database = /data/blocks_5.0/blocks.dat
+#  Cluster Percentage: >= 62
+#  Entropy =   0.6979, Expected =  -0.5209
+   A  R  N  D  C  Q  E  G  H  I  L  K  M  F  P  S  T  W  Y  V  B  Z  X  *
+A  4 -1 -2 -2  0 -1 -1  0 -2 -1 -1 -1 -1 -2 -1  1  0 -3 -2  0 -2 -1  0 -4
+R -1  5  0 -2 -3  1  0 -2  0 -3 -2  2 -1 -3 -2 -1 -1 -3 -2 -3 -1  0 -1 -4
+N -2  0  6  1 -3  0  0  0  1 -3 -3  0 -2 -3 -2  1  0 -4 -2 -3  3  0 -1 -4
+D -2 -2  1  6 -3  0  2 -1 -1 -3 -4 -1 -3 -3 -1  0 -1 -4 -3 -3  4  1 -1 -4
+C  0 -3 -3 -3  9 -3 -4 -3 -3 -1 -1 -3 -1 -2 -3 -1 -1 -2 -2 -1 -3 -3 -2 -4
+Q -1  1  0  0 -3  5  2 -2  0 -3 -2  1  0 -3 -1  0 -1 -2 -1 -2  0  3 -1 -4
+E -1  0  0  2 -4  2  5 -2  0 -3 -3  1 -2 -3 -1  0 -1 -3 -2 -2  1  4 -1 -4
+G  0 -2  0 -1 -3 -2 -2  6 -2 -4 -4 -2 -3 -3 -2  0 -2 -2 -3 -3 -1 -2 -1 -4
+H -2  0  1 -1 -3  0  0 -2  8 -3 -3 -1 -2 -1 -2 -1 -2 -2  2 -3  0  0 -1 -4
+I -1 -3 -3 -3 -1 -3 -3 -4 -3  4  2 -3  1  0 -3 -2 -1 -3 -1  3 -3 -3 -1 -4
+L -1 -2 -3 -4 -1 -2 -3 -4 -3  2  4 -2  2  0 -3 -2 -1 -2 -1  1 -4 -3 -1 -4
+K -1  2  0 -1 -3  1  1 -2 -1 -3 -2  5 -1 -3 -1  0 -1 -3 -2 -2  0  1 -1 -4
+M -1 -1 -2 -3 -1  0 -2 -3 -2  1  2 -1  5  0 -2 -1 -1 -1 -1  1 -3 -1 -1 -4
+F -2 -3 -3 -3 -2 -3 -3 -3 -1  0  0 -3  0  6 -4 -2 -2  1  3 -1 -3 -3 -1 -4
+P -1 -2 -2 -1 -3 -1 -1 -2 -2 -3 -3 -1 -2 -4  7 -1 -1 -4 -3 -2 -2 -1 -2 -4
+S  1 -1  1  0 -1  0  0  0 -1 -2 -2  0 -1 -2 -1  4  1 -3 -2 -2  0  0  0 -4
+T  0 -1  0 -1 -1 -1 -1 -2 -2 -1 -1 -1 -1 -2 -1  1  5 -2 -2  0 -1 -1  0 -4
+W -3 -3 -4 -4 -2 -2 -3 -2 -2 -3 -2 -3 -1  1 -4 -3 -2 11  2 -3 -4 -3 -2 -4
+Y -2 -2 -2 -3 -2 -1 -2 -3  2 -1 -1 -2 -1  3 -3 -2 -2  2  7 -1 -3 -2 -1 -4
+V  0 -3 -3 -3 -1 -2 -2 -3 -3  3  1 -2  1 -1 -2 -2  0 -3 -1  4 -3 -2 -1 -4
+B -2 -1  3  4 -3  0  1 -1  0 -3 -4  0 -3 -3 -2  0 -1 -4 -3 -3  4  1 -1 -4
+Z -1  0  0  1 -3  3  4 -2  0 -3 -3  1 -1 -3 -1  0 -1 -3 -2 -2  1  4 -1 -4
+X  0 -1 -1 -1 -2 -1 -1 -1 -1 -1 -1 -1 -1 -1 -2  0  0 -2 -1 -1 -1 -1 -1 -4
+* -4 -4 -4 -4 -4 -4 -4 -4 -4 -4 -4 -4 -4 -4 -4 -4 -4 -4 -4 -4 -4 -4 -4  1
+";
+
+/// PAM250 in the NCBI text format
+const PAM250: &str = "\
+#
+# This matrix was produced by \"pam\" Version 1.0.6 [28-Jul-93]
+#
+# PAM 250 substitution matrix, scale = ln(2)/3 = 0.231049
+#
+# Expected score = -0.844, Entropy = 0.354 bits
+#
+# Lowest score = -8, Highest score = 17
+#
+   A  R  N  D  C  Q  E  G  H  I  L  K  M  F  P  S  T  W  Y  V  B  Z  X  *
+A  2 -2  0  0 -2  0  0  1 -1 -1 -2 -1 -1 -3  1  1  1 -6 -3  0  0  0  0 -8
+R -2  6  0 -1 -4  1 -1 -3  2 -2 -3  3  0 -4  0  0 -1  2 -4 -2 -1  0 -1 -8
+N  0  0  2  2 -4  1  1  0  2 -2 -3  1 -2 -3  0  1  0 -4 -2 -2  2  1  0 -8
+D  0 -1  2  4 -5  2  3  1  1 -2 -4  0 -3 -6 -1  0  0 -7 -4 -2  3  3 -1 -8
+C -2 -4 -4 -5 12 -5 -5 -3 -3 -2 -6 -5 -5 -4 -3  0 -2 -8  0 -2 -4 -5 -3 -8
+Q  0  1  1  2 -5  4  2 -1  3 -2 -2  1 -1 -5  0 -1 -1 -5 -4 -2  1  3 -1 -8
+E  0 -1  1  3 -5  2  4  0  1 -2 -3  0 -2 -5 -1  0  0 -7 -4 -2  3  3 -1 -8
+G  1 -3  0  1 -3 -1  0  5 -2 -3 -4 -2 -3 -5  0  1  0 -7 -5 -1  0  0 -1 -8
+H -1  2  2  1 -3  3  1 -2  6 -2 -2  0 -2 -2  0 -1 -1 -3  0 -2  1  2 -1 -8
+I -1 -2 -2 -2 -2 -2 -2 -3 -2  5  2 -2  2  1 -2 -1  0 -5 -1  4 -2 -2 -1 -8
+L -2 -3 -3 -4 -6 -2 -3 -4 -2  2  6 -3  4  2 -3 -3 -2 -2 -1  2 -3 -3 -1 -8
+K -1  3  1  0 -5  1  0 -2  0 -2 -3  5  0 -5 -1  0  0 -3 -4 -2  1  0 -1 -8
+M -1  0 -2 -3 -5 -1 -2 -3 -2  2  4  0  6  0 -2 -2 -1 -4 -2  2 -2 -2 -1 -8
+F -3 -4 -3 -6 -4 -5 -5 -5 -2  1  2 -5  0  9 -5 -3 -3  0  7 -1 -4 -5 -2 -8
+P  1  0  0 -1 -3  0 -1  0  0 -2 -3 -1 -2 -5  6  1  0 -6 -5 -1 -1  0 -1 -8
+S  1  0  1  0  0 -1  0  1 -1 -1 -3  0 -2 -3  1  2  1 -2 -3 -1  0  0  0 -8
+T  1 -1  0  0 -2 -1  0  0 -1  0 -2  0 -1 -3  0  1  3 -5 -3  0  0 -1  0 -8
+W -6  2 -4 -7 -8 -5 -7 -7 -3 -5 -2 -3 -4  0 -6 -2 -5 17  0 -6 -5 -6 -4 -8
+Y -3 -4 -2 -4  0 -4 -4 -5  0 -1 -1 -4 -2  7 -5 -3 -3  0 10 -2 -3 -4 -2 -8
+V  0 -2 -2 -2 -2 -2 -2 -1 -2  4  2 -2  2 -1 -1 -1  0 -6 -2  4 -2 -2 -1 -8
+B  0 -1  2  3 -4  1  3  0  1 -2 -3  1 -2 -4 -1  0  0 -5 -3 -2  3  2 -1 -8
+Z  0  0  1  3 -5  3  3  0  2 -2 -3  0 -2 -5  0  0 -1 -6 -4 -2  2  3 -1 -8
+X  0 -1  0 -1 -3 -1 -1 -1 -1 -1 -1 -1 -1 -2 -1  0  0 -4 -2 -1 -1 -1 -1 -8
+* -8 -8 -8 -8 -8 -8 -8 -8 -8 -8 -8 -8 -8 -8 -8 -8 -8 -8 -8 -8 -8 -8 -8  1
+";
+
+/// Scores of aligning two elements, used by the score-maximizing aligners
+///
+/// Higher scores mean more similar elements, so they are usually positive
+/// for equal elements and negative for unrelated ones.
+pub trait SubstitutionScore<T> {
+    /// Get the score of aligning an element of the first word with an element of the second word
+    fn score(&self, first: &T, second: &T) -> i32;
+}
+
+/// Error returned when a substitution matrix cannot be parsed
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParseMatrixError {
+    /// Text contains no header line listing the residues
+    MissingHeader,
+    /// Residue is not a single ASCII character
+    InvalidResidue { line: usize },
+    /// Score is not an integer
+    InvalidScore { line: usize },
+    /// Row has a different number of scores than the header has residues
+    RowLength {
+        line: usize,
+        expected: usize,
+        found: usize,
+    },
+    /// Residue from the header has no row
+    MissingRow { residue: char },
+}
+
+impl fmt::Display for ParseMatrixError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ParseMatrixError::MissingHeader => write!(f, "substitution matrix has no header"),
+            ParseMatrixError::InvalidResidue { line } => {
+                write!(f, "invalid residue in line {}", line)
+            }
+            ParseMatrixError::InvalidScore { line } => write!(f, "invalid score in line {}", line),
+            ParseMatrixError::RowLength {
+                line,
+                expected,
+                found,
+            } => write!(
+                f,
+                "expected {} scores in line {}, got {}",
+                expected, line, found
+            ),
+            ParseMatrixError::MissingRow { residue } => {
+                write!(f, "missing row of residue '{}'", residue)
+            }
+        }
+    }
+}
+
+impl Error for ParseMatrixError {}
+
+/// Substitution matrix of residues, e.g. amino acids, indexed by ASCII letters
+///
+/// Lookups are case-insensitive. Residues missing from the matrix get its
+/// lowest score.
+///
+/// # Examples
+/// ```
+/// use edit_dist::{SubstitutionMatrix, SubstitutionScore};
+/// let blosum62 = SubstitutionMatrix::blosum62();
+/// assert_eq!(blosum62.score(&b'W', &b'W'), 11);
+/// assert_eq!(blosum62.score(&'a', &'R'), -1);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SubstitutionMatrix {
+    /// Row of every byte in `scores`
+    rows: [Option<usize>; 256],
+    /// Number of residues
+    size: usize,
+    /// Scores of all pairs of residues, row by row
+    scores: Vec<i32>,
+    /// Lowest score of the matrix
+    min_score: i32,
+}
+
+impl SubstitutionMatrix {
+    /// Get the BLOSUM62 matrix
+    pub fn blosum62() -> Self {
+        Self::from_ncbi_str(BLOSUM62).expect("BLOSUM62 is a valid matrix")
+    }
+
+    /// Get the PAM250 matrix
+    pub fn pam250() -> Self {
+        Self::from_ncbi_str(PAM250).expect("PAM250 is a valid matrix")
+    }
+
+    /// Parse a substitution matrix in the NCBI text format
+    ///
+    /// Lines starting with `#` and empty lines are skipped. The first other
+    /// line lists the residues, every next one starts with a residue followed
+    /// by its scores against all residues from the header.
+    ///
+    /// # Arguments
+    /// * `text` - Matrix in the NCBI text format
+    ///
+    /// # Examples
+    /// ```
+    /// use edit_dist::{SubstitutionMatrix, SubstitutionScore};
+    /// let text = "
+    /// ## Simple DNA matrix
+    ///    A  C  G  T
+    /// A  5 -4 -4 -4
+    /// C -4  5 -4 -4
+    /// G -4 -4  5 -4
+    /// T -4 -4 -4  5
+    /// ";
+    /// let matrix = SubstitutionMatrix::from_ncbi_str(text).unwrap();
+    /// assert_eq!(matrix.score(&b'g', &b'G'), 5);
+    /// assert_eq!(matrix.score(&b'A', &b'N'), -4);
+    /// ```
+    pub fn from_ncbi_str(text: &str) -> Result<Self, ParseMatrixError> {
+        let mut lines = text
+            .lines()
+            .enumerate()
+            .map(|(index, line)| (index + 1, line.trim()))
+            .filter(|(_, line)| !line.is_empty() && !line.starts_with('#'));
+
+        let (header_line, header) = lines.next().ok_or(ParseMatrixError::MissingHeader)?;
+        let residues = header
+            .split_whitespace()
+            .map(|token| residue(token, header_line))
+            .collect::<Result<Vec<u8>, _>>()?;
+
+        let size = residues.len();
+        let mut rows = [None; 256];
+        for (row, &residue) in residues.iter().enumerate() {
+            rows[usize::from(residue.to_ascii_uppercase())] = Some(row);
+            rows[usize::from(residue.to_ascii_lowercase())] = Some(row);
+        }
+
+        let mut scores = vec![0; size * size];
+        let mut filled = vec![false; size];
+        for (line, text) in lines {
+            let mut tokens = text.split_whitespace();
+            let row_residue = residue(tokens.next().unwrap_or_default(), line)?;
+            let row =
+                rows[usize::from(row_residue)].ok_or(ParseMatrixError::InvalidResidue { line })?;
+
+            let row_scores = tokens
+                .map(|token| {
+                    token
+                        .parse::<i32>()
+                        .map_err(|_| ParseMatrixError::InvalidScore { line })
+                })
+                .collect::<Result<Vec<i32>, _>>()?;
+            if row_scores.len() != size {
+                return Err(ParseMatrixError::RowLength {
+                    line,
+                    expected: size,
+                    found: row_scores.len(),
+                });
+            }
+
+            scores[row * size..(row + 1) * size].copy_from_slice(&row_scores);
+            filled[row] = true;
+        }
+
+        if let Some(row) = filled.iter().position(|&filled| !filled) {
+            return Err(ParseMatrixError::MissingRow {
+                residue: char::from(residues[row]),
+            });
+        }
+
+        Ok(SubstitutionMatrix {
+            rows,
+            size,
+            min_score: scores.iter().copied().min().unwrap_or(0),
+            scores,
+        })
+    }
+
+    /// Get the score of aligning two residues
+    ///
+    /// # Arguments
+    /// * `first` - Residue of the first word
+    /// * `second` - Residue of the second word
+    fn residue_score(&self, first: u8, second: u8) -> i32 {
+        match (
+            self.rows[usize::from(first)],
+            self.rows[usize::from(second)],
+        ) {
+            (Some(row), Some(column)) => self.scores[row * self.size + column],
+            _ => self.min_score,
+        }
+    }
+}
+
+impl SubstitutionScore<u8> for SubstitutionMatrix {
+    fn score(&self, first: &u8, second: &u8) -> i32 {
+        self.residue_score(*first, *second)
+    }
+}
+
+impl SubstitutionScore<char> for SubstitutionMatrix {
+    fn score(&self, first: &char, second: &char) -> i32 {
+        if first.is_ascii() && second.is_ascii() {
+            self.residue_score(*first as u8, *second as u8)
+        } else {
+            self.min_score
+        }
+    }
+}
+
+/// Parse a residue from a single ASCII character
+///
+/// # Arguments
+/// * `token` - Token holding the residue
+/// * `line` - Number of the line the token comes from
+fn residue(token: &str, line: usize) -> Result<u8, ParseMatrixError> {
+    match token.as_bytes() {
+        [residue] if residue.is_ascii_graphic() => Ok(*residue),
+        _ => Err(ParseMatrixError::InvalidResidue { line }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const RESIDUES: &[u8] = b"ARNDCQEGHILKMFPSTWYVBZX*";
+
+    #[test]
+    fn substitution_test_builtin_matrices_are_symmetric() {
+        for matrix in [SubstitutionMatrix::blosum62(), SubstitutionMatrix::pam250()].iter() {
+            for first in RESIDUES {
+                for second in RESIDUES {
+                    assert_eq!(matrix.score(first, second), matrix.score(second, first));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn substitution_test_builtin_scores() {
+        let blosum62 = SubstitutionMatrix::blosum62();
+        assert_eq!(blosum62.score(&b'C', &b'C'), 9);
+        assert_eq!(blosum62.score(&b'w', &b'f'), 1);
+        assert_eq!(blosum62.score(&b'A', &b'J'), -4);
+        assert_eq!(blosum62.score(&'ł', &'A'), -4);
+
+        let pam250 = SubstitutionMatrix::pam250();
+        assert_eq!(pam250.score(&b'W', &b'W'), 17);
+        assert_eq!(pam250.score(&b'C', &b'W'), -8);
+        assert_eq!(pam250.score(&'F', &'Y'), 7);
+    }
+
+    #[test]
+    fn substitution_test_parse_errors() {
+        assert_eq!(
+            SubstitutionMatrix::from_ncbi_str("# only a comment\n"),
+            Err(ParseMatrixError::MissingHeader)
+        );
+        assert_eq!(
+            SubstitutionMatrix::from_ncbi_str("  A  C\nA 1 x\nC 0 1\n"),
+            Err(ParseMatrixError::InvalidScore { line: 2 })
+        );
+        assert_eq!(
+            SubstitutionMatrix::from_ncbi_str("  A  C\nA 1 0\nC 0\n"),
+            Err(ParseMatrixError::RowLength {
+                line: 3,
+                expected: 2,
+                found: 1
+            })
+        );
+        assert_eq!(
+            SubstitutionMatrix::from_ncbi_str("  A  C\nA 1 0\n"),
+            Err(ParseMatrixError::MissingRow { residue: 'C' })
+        );
+        assert_eq!(
+            SubstitutionMatrix::from_ncbi_str("  A  CG\nA 1 0\n"),
+            Err(ParseMatrixError::InvalidResidue { line: 1 })
+        );
+    }
+}
@@ -0,0 +1,265 @@
+use std::collections::{HashMap, VecDeque};
+use std::hash::Hash;
+
+/// Deterministic Levenshtein automaton accepting all words within given distance from a query
+///
+/// States are rows of the distance matrix of the query and the prefix read so
+/// far, with values above the maximal distance capped. The whole automaton is
+/// built up front, so stepping through it is a single table lookup. Elements
+/// missing from the query all behave the same and share their transitions.
+///
+/// It can be intersected with a sorted dictionary, a trie or a finite state
+/// transducer: whenever [`can_match`](LevenshteinAutomaton::can_match) returns
+/// `false`, no word with the current prefix is accepted and the whole
+/// subtree can be skipped.
+///
+/// # Examples
+/// ```
+/// use edit_dist::LevenshteinAutomaton;
+///
+/// let automaton = LevenshteinAutomaton::new("kitten".chars(), 2);
+/// assert_eq!(automaton.eval("sitting".chars()), None);
+/// assert_eq!(automaton.eval("mitten".chars()), Some(1));
+///
+/// // Stepping through the automaton one element at a time
+/// let mut state = automaton.start();
+/// for c in "kitchen".chars() {
+///     state = automaton.step(state, &c);
+/// }
+/// assert_eq!(automaton.distance(state), Some(2));
+///
+/// // No word starting with "xyz" is within distance 2 from "kitten"
+/// let mut state = automaton.start();
+/// for c in "xyz".chars() {
+///     state = automaton.step(state, &c);
+/// }
+/// assert!(!automaton.can_match(state));
+/// ```
+#[derive(Debug, Clone)]
+pub struct LevenshteinAutomaton<T> {
+    /// Class of every distinct element of the query, the other elements share the last class
+    classes: HashMap<T, usize>,
+    /// Transition table, the next state is at `state * (classes.len() + 1) + class`
+    transitions: Vec<usize>,
+    /// Distance between the query and the word read so far, if it is small enough
+    distances: Vec<Option<usize>>,
+    /// Whether any extension of the word read so far may be accepted
+    live: Vec<bool>,
+}
+
+impl<T: Eq + Hash + Clone> LevenshteinAutomaton<T> {
+    /// Build a Levenshtein automaton for a query
+    ///
+    /// # Arguments
+    /// * `query` - Word the accepted words are compared with
+    /// * `max_distance` - Largest accepted distance
+    pub fn new(query: impl IntoIterator<Item = T>, max_distance: usize) -> Self {
+        let query: Vec<T> = query.into_iter().collect();
+
+        let mut classes = HashMap::new();
+        for element in &query {
+            let class = classes.len();
+            classes.entry(element.clone()).or_insert(class);
+        }
+        let class_count = classes.len() + 1;
+        let query_classes: Vec<usize> = query.iter().map(|element| classes[element]).collect();
+
+        let cap = max_distance.saturating_add(1);
+        let start: Vec<usize> = (0..=query.len()).map(|i| i.min(cap)).collect();
+
+        let mut automaton = LevenshteinAutomaton {
+            classes,
+            transitions: Vec::new(),
+            distances: Vec::new(),
+            live: Vec::new(),
+        };
+        let mut states = HashMap::new();
+        let mut pending = VecDeque::new();
+        automaton.add_state(&mut states, &mut pending, start, max_distance);
+
+        while let Some(row) = pending.pop_front() {
+            for class in 0..class_count {
+                let mut next = Vec::with_capacity(row.len());
+                next.push((row[0] + 1).min(cap));
+
+                for i in 1..row.len() {
+                    let the_same_letter = query_classes[i - 1] == class;
+                    let cost = if the_same_letter { 0 } else { 1 };
+
+                    let value = (row[i - 1] + cost).min(row[i] + 1).min(next[i - 1] + 1);
+                    next.push(value.min(cap));
+                }
+
+                let state = automaton.add_state(&mut states, &mut pending, next, max_distance);
+                automaton.transitions.push(state);
+            }
+        }
+
+        automaton
+    }
+
+    /// Get the state the automaton starts in
+    pub fn start(&self) -> usize {
+        0
+    }
+
+    /// Get the state reached after reading an element
+    ///
+    /// # Arguments
+    /// * `state` - Current state
+    /// * `element` - Element being read
+    pub fn step(&self, state: usize, element: &T) -> usize {
+        let class_count = self.classes.len() + 1;
+        let class = self
+            .classes
+            .get(element)
+            .copied()
+            .unwrap_or(class_count - 1);
+
+        self.transitions[state * class_count + class]
+    }
+
+    /// Check whether the word read so far is accepted
+    ///
+    /// # Arguments
+    /// * `state` - Current state
+    pub fn is_match(&self, state: usize) -> bool {
+        self.distances[state].is_some()
+    }
+
+    /// Get the distance between the query and the word read so far, if it is accepted
+    ///
+    /// # Arguments
+    /// * `state` - Current state
+    pub fn distance(&self, state: usize) -> Option<usize> {
+        self.distances[state]
+    }
+
+    /// Check whether the word read so far or any of its extensions may be accepted
+    ///
+    /// # Arguments
+    /// * `state` - Current state
+    pub fn can_match(&self, state: usize) -> bool {
+        self.live[state]
+    }
+
+    /// Get number of states of the automaton
+    pub fn state_count(&self) -> usize {
+        self.distances.len()
+    }
+
+    /// Run the automaton on a whole word
+    ///
+    /// Returns the distance between the query and the word, or `None` if it
+    /// is larger than the maximal distance.
+    ///
+    /// # Arguments
+    /// * `word` - Word to check
+    pub fn eval(&self, word: impl IntoIterator<Item = T>) -> Option<usize> {
+        let mut state = self.start();
+        for element in word {
+            if !self.can_match(state) {
+                return None;
+            }
+            state = self.step(state, &element);
+        }
+
+        self.distance(state)
+    }
+
+    /// Get the state of a matrix row, registering it if it was not seen before
+    fn add_state(
+        &mut self,
+        states: &mut HashMap<Vec<usize>, usize>,
+        pending: &mut VecDeque<Vec<usize>>,
+        row: Vec<usize>,
+        max_distance: usize,
+    ) -> usize {
+        if let Some(&state) = states.get(&row) {
+            return state;
+        }
+
+        let state = self.distances.len();
+        let distance = row[row.len() - 1];
+        self.distances
+            .push(Some(distance).filter(|&distance| distance <= max_distance));
+        self.live
+            .push(row.iter().any(|&value| value <= max_distance));
+
+        states.insert(row.clone(), state);
+        pending.push_back(row);
+        state
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::levenshtein;
+
+    /// All words over an alphabet with at most given length
+    fn all_words(alphabet: &[char], max_length: usize) -> Vec<Vec<char>> {
+        let mut words = vec![vec![]];
+        let mut last = vec![vec![]];
+        for _ in 0..max_length {
+            last = last
+                .iter()
+                .flat_map(|word: &Vec<char>| {
+                    alphabet.iter().map(move |&c| {
+                        let mut word = word.clone();
+                        word.push(c);
+                        word
+                    })
+                })
+                .collect();
+            words.extend(last.iter().cloned());
+        }
+        words
+    }
+
+    #[test]
+    fn automaton_test_agrees_with_levenshtein() {
+        let words = all_words(&['a', 'b', 'c', 'x'], 6);
+        for query in ["abca", "", "aaa", "cab"].iter() {
+            for max_distance in 0..3 {
+                let automaton = LevenshteinAutomaton::new(query.chars(), max_distance);
+                for word in &words {
+                    let dist = levenshtein(query.chars(), word.iter().copied());
+                    let expected = Some(dist).filter(|&dist| dist <= max_distance);
+                    assert_eq!(automaton.eval(word.iter().copied()), expected);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn automaton_test_dead_prefixes() {
+        let automaton = LevenshteinAutomaton::new("abc".chars(), 1);
+        for prefix in all_words(&['a', 'b', 'c', 'x'], 4) {
+            let mut state = automaton.start();
+            for c in &prefix {
+                state = automaton.step(state, c);
+            }
+            if automaton.can_match(state) {
+                continue;
+            }
+
+            // No extension of a dead prefix is accepted
+            for suffix in all_words(&['a', 'b', 'c', 'x'], 3) {
+                let word = prefix.iter().chain(suffix.iter());
+                assert!(levenshtein("abc".chars(), word.copied()) > 1);
+            }
+        }
+    }
+
+    #[test]
+    fn automaton_test_sorted_dictionary() {
+        let dictionary = ["apple", "apply", "ample", "maple", "people", "triple"];
+        let automaton = LevenshteinAutomaton::new("appel".chars(), 2);
+        let found: Vec<(&str, usize)> = dictionary
+            .iter()
+            .filter_map(|word| Some((*word, automaton.eval(word.chars())?)))
+            .collect();
+        assert_eq!(found, vec![("apple", 2), ("apply", 2)]);
+    }
+}
@@ -0,0 +1,331 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// Number of pattern elements handled by a single machine word
+const WORD_SIZE: usize = 64;
+
+/// Calculate Levenshtein distance for two words using the bit-parallel algorithm
+///
+/// The shorter word becomes the pattern. Patterns of up to 64 elements are
+/// handled by Myers' algorithm in a single machine word, longer ones by its
+/// blocked variant (Hyyrö), so the distance is found in `O(⌈m / 64⌉ · n)` steps
+/// instead of `O(m · n)`. Building the per-element match masks requires
+/// hashable elements.
+///
+/// # Arguments
+/// * `first_word` - First word
+/// * `second_word` - Second word
+///
+/// # Examples
+/// ```
+/// use edit_dist::levenshtein_bit_parallel;
+/// let dist = levenshtein_bit_parallel(
+///     "sitting".chars(),
+///     "kitten".chars()
+/// );
+/// assert_eq!(dist, 3);
+/// ```
+pub fn levenshtein_bit_parallel<T: Eq + Hash>(
+    first_word: impl IntoIterator<Item = T>,
+    second_word: impl IntoIterator<Item = T>,
+) -> usize {
+    let first_word: Vec<T> = first_word.into_iter().collect();
+    let second_word: Vec<T> = second_word.into_iter().collect();
+
+    bit_parallel_slice(&first_word, &second_word)
+}
+
+/// Calculate Levenshtein distance for two borrowed words using the bit-parallel algorithm
+///
+/// # Arguments
+/// * `first_word` - First word
+/// * `second_word` - Second word
+pub(crate) fn bit_parallel_slice<T: Eq + Hash>(first_word: &[T], second_word: &[T]) -> usize {
+    let (pattern, text) = if first_word.len() <= second_word.len() {
+        (first_word, second_word)
+    } else {
+        (second_word, first_word)
+    };
+
+    hashed(pattern.iter(), pattern.len(), text.iter())
+}
+
+/// Calculate Levenshtein distance for two strings using the bit-parallel algorithm
+///
+/// Strings are compared by Unicode scalar values, and neither of them is copied.
+///
+/// # Arguments
+/// * `first_word` - First word
+/// * `second_word` - Second word
+pub(crate) fn bit_parallel_str(first_word: &str, second_word: &str) -> usize {
+    let first_length = first_word.chars().count();
+    let second_length = second_word.chars().count();
+
+    if first_length <= second_length {
+        hashed(first_word.chars(), first_length, second_word.chars())
+    } else {
+        hashed(second_word.chars(), second_length, first_word.chars())
+    }
+}
+
+/// Calculate Levenshtein distance for two byte slices using the bit-parallel algorithm
+///
+/// Match masks are kept in a table indexed directly by byte values.
+///
+/// # Arguments
+/// * `first_word` - First word
+/// * `second_word` - Second word
+pub(crate) fn bit_parallel_bytes(first_word: &[u8], second_word: &[u8]) -> usize {
+    let (pattern, text) = if first_word.len() <= second_word.len() {
+        (first_word, second_word)
+    } else {
+        (second_word, first_word)
+    };
+
+    if pattern.is_empty() {
+        return text.len();
+    }
+
+    if pattern.len() <= WORD_SIZE {
+        let mut masks = [0u64; 256];
+        for (i, &element) in pattern.iter().enumerate() {
+            masks[element as usize] |= 1 << i;
+        }
+
+        single_word(pattern.len(), text.iter(), |&&element| {
+            masks[element as usize]
+        })
+    } else {
+        let blocks = pattern.len().div_ceil(WORD_SIZE);
+        let mut masks = vec![0u64; 256 * blocks];
+        for (i, &element) in pattern.iter().enumerate() {
+            masks[element as usize * blocks + i / WORD_SIZE] |= 1 << (i % WORD_SIZE);
+        }
+
+        blocked(pattern.len(), text.iter(), |&&element| {
+            let start = element as usize * blocks;
+            &masks[start..start + blocks]
+        })
+    }
+}
+
+/// Calculate Levenshtein distance keeping the match masks in a hash map
+///
+/// # Arguments
+/// * `pattern` - Elements of the shorter word
+/// * `pattern_length` - Number of elements in `pattern`
+/// * `text` - Elements of the longer word
+fn hashed<S: Eq + Hash>(
+    pattern: impl Iterator<Item = S>,
+    pattern_length: usize,
+    text: impl Iterator<Item = S>,
+) -> usize {
+    if pattern_length == 0 {
+        return text.count();
+    }
+
+    if pattern_length <= WORD_SIZE {
+        let mut masks: HashMap<S, u64> = HashMap::new();
+        for (i, element) in pattern.enumerate() {
+            *masks.entry(element).or_insert(0) |= 1 << i;
+        }
+
+        single_word(pattern_length, text, |element| {
+            masks.get(element).copied().unwrap_or(0)
+        })
+    } else {
+        let blocks = pattern_length.div_ceil(WORD_SIZE);
+        let mut masks: HashMap<S, Vec<u64>> = HashMap::new();
+        for (i, element) in pattern.enumerate() {
+            masks.entry(element).or_insert_with(|| vec![0; blocks])[i / WORD_SIZE] |=
+                1 << (i % WORD_SIZE);
+        }
+
+        let no_matches = vec![0; blocks];
+        blocked(pattern_length, text, |element| {
+            masks.get(element).unwrap_or(&no_matches)
+        })
+    }
+}
+
+/// Run Myers' algorithm for a pattern fitting in a single machine word
+///
+/// # Arguments
+/// * `pattern_length` - Length of the pattern, between 1 and 64
+/// * `text` - Elements of the text
+/// * `mask` - Bit mask of pattern positions equal to the given text element
+fn single_word<S>(
+    pattern_length: usize,
+    text: impl Iterator<Item = S>,
+    mask: impl Fn(&S) -> u64,
+) -> usize {
+    let last_bit = 1u64 << (pattern_length - 1);
+    let mut vertical_positive = !0u64;
+    let mut vertical_negative = 0u64;
+    let mut distance = pattern_length;
+
+    for element in text {
+        let equal = mask(&element);
+        let vertical = equal | vertical_negative;
+        let horizontal = ((equal & vertical_positive).wrapping_add(vertical_positive)
+            ^ vertical_positive)
+            | equal;
+        let mut horizontal_positive = vertical_negative | !(horizontal | vertical_positive);
+        let mut horizontal_negative = vertical_positive & horizontal;
+
+        if horizontal_positive & last_bit != 0 {
+            distance += 1;
+        } else if horizontal_negative & last_bit != 0 {
+            distance -= 1;
+        }
+
+        // The top row of the matrix grows by one in every column
+        horizontal_positive = (horizontal_positive << 1) | 1;
+        horizontal_negative <<= 1;
+
+        vertical_positive = horizontal_negative | !(vertical | horizontal_positive);
+        vertical_negative = horizontal_positive & vertical;
+    }
+
+    distance
+}
+
+/// Run the blocked variant of Myers' algorithm for patterns of any length
+///
+/// # Arguments
+/// * `pattern_length` - Length of the pattern, at least 1
+/// * `text` - Elements of the text
+/// * `masks` - Bit masks of pattern positions equal to the given text element, one per block
+fn blocked<'m, S>(
+    pattern_length: usize,
+    text: impl Iterator<Item = S>,
+    masks: impl Fn(&S) -> &'m [u64],
+) -> usize {
+    let blocks = pattern_length.div_ceil(WORD_SIZE);
+    let last_bit = 1u64 << ((pattern_length - 1) % WORD_SIZE);
+    let mut vertical_positive = vec![!0u64; blocks];
+    let mut vertical_negative = vec![0u64; blocks];
+    let mut distance = pattern_length;
+
+    for element in text {
+        let masks = masks(&element);
+        // Horizontal difference entering the block from above
+        let mut carry: i8 = 1;
+
+        for block in 0..blocks {
+            let (positive, negative) = (vertical_positive[block], vertical_negative[block]);
+            let mut equal = masks[block];
+            let vertical = equal | negative;
+            if carry < 0 {
+                equal |= 1;
+            }
+
+            let horizontal = ((equal & positive).wrapping_add(positive) ^ positive) | equal;
+            let mut horizontal_positive = negative | !(horizontal | positive);
+            let mut horizontal_negative = positive & horizontal;
+
+            let high_bit = if block == blocks - 1 {
+                last_bit
+            } else {
+                1 << (WORD_SIZE - 1)
+            };
+            let carry_out = if horizontal_positive & high_bit != 0 {
+                1
+            } else if horizontal_negative & high_bit != 0 {
+                -1
+            } else {
+                0
+            };
+
+            horizontal_positive <<= 1;
+            horizontal_negative <<= 1;
+            if carry < 0 {
+                horizontal_negative |= 1;
+            } else if carry > 0 {
+                horizontal_positive |= 1;
+            }
+
+            vertical_positive[block] = horizontal_negative | !(vertical | horizontal_positive);
+            vertical_negative[block] = horizontal_positive & vertical;
+            carry = carry_out;
+        }
+
+        if carry > 0 {
+            distance += 1;
+        } else if carry < 0 {
+            distance -= 1;
+        }
+    }
+
+    distance
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::levenshtein;
+
+    /// Generate a deterministic pseudo-random word over a small alphabet
+    fn word(seed: &mut u64, length: usize) -> Vec<u8> {
+        (0..length)
+            .map(|_| {
+                *seed = seed
+                    .wrapping_mul(6364136223846793005)
+                    .wrapping_add(1442695040888963407);
+                b'a' + (*seed >> 61) as u8
+            })
+            .collect()
+    }
+
+    #[test]
+    fn bit_parallel_test_short() {
+        let a = "honda".chars();
+        let b = "hyundai".chars();
+        assert_eq!(levenshtein_bit_parallel(a, b), 3);
+        assert_eq!(levenshtein_bit_parallel("".chars(), "abc".chars()), 3);
+        assert_eq!(levenshtein_bit_parallel("abc".chars(), "".chars()), 3);
+    }
+
+    #[test]
+    fn bit_parallel_test_word_boundaries() {
+        let mut seed = 7;
+        for &length in [63, 64, 65, 127, 128, 129, 200].iter() {
+            let first = word(&mut seed, length);
+            let second = word(&mut seed, length + 3);
+            assert_eq!(
+                levenshtein_bit_parallel(first.iter(), second.iter()),
+                levenshtein(first.iter(), second.iter())
+            );
+        }
+    }
+
+    #[test]
+    fn bit_parallel_test_bytes_and_str() {
+        let mut seed = 3;
+        for &length in [0, 5, 64, 65, 150].iter() {
+            let first = word(&mut seed, length);
+            let second = word(&mut seed, length / 2 + 1);
+            let expected = levenshtein(first.iter(), second.iter());
+            assert_eq!(bit_parallel_bytes(&first, &second), expected);
+
+            let first = String::from_utf8(first).unwrap().replace('a', "ą");
+            let second = String::from_utf8(second).unwrap().replace('a', "ą");
+            assert_eq!(bit_parallel_str(&first, &second), expected);
+        }
+    }
+
+    #[test]
+    fn bit_parallel_test_agrees_with_levenshtein() {
+        let mut seed = 42;
+        for _ in 0..200 {
+            let first_length = (seed >> 33) as usize % 150;
+            let first = word(&mut seed, first_length);
+            let second_length = (seed >> 33) as usize % 150;
+            let second = word(&mut seed, second_length);
+            assert_eq!(
+                levenshtein_bit_parallel(first.iter(), second.iter()),
+                levenshtein(first.iter(), second.iter())
+            );
+        }
+    }
+}
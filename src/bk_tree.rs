@@ -0,0 +1,272 @@
+use std::collections::{BTreeMap, BinaryHeap};
+
+use crate::RawDistance;
+
+/// Single word stored in a BK-tree
+struct Node<T> {
+    word: Vec<T>,
+    /// Child node indices keyed by their distance to this node's word
+    children: BTreeMap<usize, usize>,
+}
+
+/// BK-tree: index of words for fast lookup of the ones close to a query
+///
+/// The tree relies on the triangle inequality, so the metric has to be a true
+/// metric, e.g. [`Levenshtein`](crate::Levenshtein),
+/// [`DamerauLevenshtein`](crate::DamerauLevenshtein) or [`Indel`](crate::Indel),
+/// but not [`OptimalStringAlignment`](crate::OptimalStringAlignment).
+///
+/// # Examples
+/// ```
+/// use edit_dist::{BkTree, Levenshtein};
+///
+/// let mut tree = BkTree::new(Levenshtein::default());
+/// for word in ["book", "books", "cake", "boo", "cape", "cart"].iter() {
+///     tree.insert(word.chars());
+/// }
+///
+/// let query: Vec<char> = "bo".chars().collect();
+/// let found: Vec<(String, usize)> = tree
+///     .find_within(&query, 1)
+///     .into_iter()
+///     .map(|(word, distance)| (word.iter().collect(), distance))
+///     .collect();
+/// assert_eq!(found, vec![(String::from("boo"), 1)]);
+/// ```
+pub struct BkTree<T, M> {
+    metric: M,
+    nodes: Vec<Node<T>>,
+}
+
+impl<T, M> BkTree<T, M>
+where
+    M: RawDistance<T, Distance = usize>,
+{
+    /// Create a new, empty instance of BkTree
+    ///
+    /// # Arguments
+    /// * `metric` - Metric used to compare words
+    pub fn new(metric: M) -> Self {
+        BkTree {
+            metric,
+            nodes: Vec::new(),
+        }
+    }
+
+    /// Get number of words stored in the tree
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    /// Check whether the tree is empty
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+
+    /// Insert a word into the tree
+    ///
+    /// Returns `false` if the tree already contained a word at distance 0.
+    ///
+    /// # Arguments
+    /// * `word` - Word to insert
+    pub fn insert(&mut self, word: impl IntoIterator<Item = T>) -> bool {
+        let word: Vec<T> = word.into_iter().collect();
+        if self.nodes.is_empty() {
+            self.push(word);
+            return true;
+        }
+
+        let mut current = 0;
+        loop {
+            let distance = self.metric.raw_distance(&self.nodes[current].word, &word);
+            if distance == 0 {
+                return false;
+            }
+
+            match self.nodes[current].children.get(&distance) {
+                Some(&child) => current = child,
+                None => {
+                    let child = self.push(word);
+                    self.nodes[current].children.insert(distance, child);
+                    return true;
+                }
+            }
+        }
+    }
+
+    /// Find all words within given distance from a query
+    ///
+    /// Results are sorted by distance, ties are kept in insertion order.
+    ///
+    /// # Arguments
+    /// * `query` - Word to look for
+    /// * `max_distance` - Largest accepted distance
+    pub fn find_within(&self, query: &[T], max_distance: usize) -> Vec<(&[T], usize)> {
+        let mut found = Vec::new();
+        let mut pending = if self.nodes.is_empty() {
+            vec![]
+        } else {
+            vec![0]
+        };
+
+        while let Some(current) = pending.pop() {
+            let node = &self.nodes[current];
+            let distance = self.metric.raw_distance(query, &node.word);
+            if distance <= max_distance {
+                found.push((current, distance));
+            }
+
+            let lowest = distance.saturating_sub(max_distance);
+            let highest = distance.saturating_add(max_distance);
+            pending.extend(
+                node.children
+                    .range(lowest..=highest)
+                    .map(|(_, &child)| child),
+            );
+        }
+
+        found.sort_unstable_by_key(|&(index, distance)| (distance, index));
+        found
+            .into_iter()
+            .map(|(index, distance)| (self.nodes[index].word.as_slice(), distance))
+            .collect()
+    }
+
+    /// Find `count` words nearest to a query
+    ///
+    /// Results are sorted by distance, ties are resolved in favour of words
+    /// inserted earlier.
+    ///
+    /// # Arguments
+    /// * `query` - Word to look for
+    /// * `count` - Maximal number of returned words
+    pub fn find_nearest(&self, query: &[T], count: usize) -> Vec<(&[T], usize)> {
+        // Max-heap of the best candidates so far, the worst one on top
+        let mut best: BinaryHeap<(usize, usize)> = BinaryHeap::with_capacity(count + 1);
+        // Nodes to visit along with a lower bound of distances in their subtrees
+        let mut pending = if self.nodes.is_empty() || count == 0 {
+            vec![]
+        } else {
+            vec![(0, 0)]
+        };
+
+        while let Some((current, lower_bound)) = pending.pop() {
+            if best.len() == count && best.peek().is_some_and(|&(worst, _)| lower_bound > worst) {
+                continue;
+            }
+
+            let node = &self.nodes[current];
+            let distance = self.metric.raw_distance(query, &node.word);
+            best.push((distance, current));
+            if best.len() > count {
+                best.pop();
+            }
+
+            let radius = if best.len() == count {
+                best.peek().map_or(usize::MAX, |&(worst, _)| worst)
+            } else {
+                usize::MAX
+            };
+            let lowest = distance.saturating_sub(radius);
+            let highest = distance.saturating_add(radius);
+            pending.extend(
+                node.children
+                    .range(lowest..=highest)
+                    .map(|(&edge, &child)| (child, edge.abs_diff(distance))),
+            );
+        }
+
+        best.into_sorted_vec()
+            .into_iter()
+            .map(|(distance, index)| (self.nodes[index].word.as_slice(), distance))
+            .collect()
+    }
+
+    /// Append a node without children and return its index
+    fn push(&mut self, word: Vec<T>) -> usize {
+        self.nodes.push(Node {
+            word,
+            children: BTreeMap::new(),
+        });
+        self.nodes.len() - 1
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{levenshtein_slice, Levenshtein};
+
+    const WORDS: [&str; 12] = [
+        "book", "books", "boo", "cake", "cape", "cart", "boon", "cook", "cool", "back", "bake",
+        "brook",
+    ];
+
+    fn tree() -> BkTree<char, Levenshtein> {
+        let mut tree = BkTree::new(Levenshtein::default());
+        for word in WORDS.iter() {
+            assert!(tree.insert(word.chars()));
+        }
+        tree
+    }
+
+    fn chars(word: &str) -> Vec<char> {
+        word.chars().collect()
+    }
+
+    #[test]
+    fn bk_tree_test_insert() {
+        let mut tree = tree();
+        assert_eq!(tree.len(), WORDS.len());
+        assert!(!tree.insert("cake".chars()));
+        assert_eq!(tree.len(), WORDS.len());
+        assert!(BkTree::<char, _>::new(Levenshtein::default()).is_empty());
+    }
+
+    #[test]
+    fn bk_tree_test_find_within_agrees_with_scan() {
+        let tree = tree();
+        for query in ["book", "cake", "xyz", "", "bok"].iter() {
+            let query = chars(query);
+            for max_distance in 0..4 {
+                let mut expected: Vec<(Vec<char>, usize)> = WORDS
+                    .iter()
+                    .map(|word| chars(word))
+                    .map(|word| {
+                        let distance = levenshtein_slice(&query, &word);
+                        (word, distance)
+                    })
+                    .filter(|&(_, distance)| distance <= max_distance)
+                    .collect();
+                expected.sort_by_key(|&(_, distance)| distance);
+
+                let found: Vec<(Vec<char>, usize)> = tree
+                    .find_within(&query, max_distance)
+                    .into_iter()
+                    .map(|(word, distance)| (word.to_vec(), distance))
+                    .collect();
+                assert_eq!(found, expected);
+            }
+        }
+    }
+
+    #[test]
+    fn bk_tree_test_find_nearest() {
+        let tree = tree();
+        let query = chars("cokk");
+        let found: Vec<usize> = tree
+            .find_nearest(&query, 3)
+            .into_iter()
+            .map(|(_, distance)| distance)
+            .collect();
+
+        let mut distances: Vec<usize> = WORDS
+            .iter()
+            .map(|word| levenshtein_slice(&query, &chars(word)))
+            .collect();
+        distances.sort_unstable();
+        assert_eq!(found, distances[..3].to_vec());
+        assert_eq!(tree.find_nearest(&query, 0), vec![]);
+        assert_eq!(tree.find_nearest(&query, 100).len(), WORDS.len());
+    }
+}
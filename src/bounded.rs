@@ -0,0 +1,138 @@
+use std::mem;
+
+/// Calculate Levenshtein distance for two words if it does not exceed a given bound
+///
+/// Only the diagonal band of width `2 * max_distance + 1` of the distance
+/// matrix is computed (Ukkonen's cut-off), and the calculation stops as soon
+/// as every cell of a row exceeds the bound.
+///
+/// # Arguments
+/// * `first_word` - First word
+/// * `second_word` - Second word
+/// * `max_distance` - Largest distance of interest
+///
+/// # Examples
+/// ```
+/// use edit_dist::levenshtein_bounded;
+/// assert_eq!(levenshtein_bounded("kitten".chars(), "sitting".chars(), 3), Some(3));
+/// assert_eq!(levenshtein_bounded("kitten".chars(), "sitting".chars(), 2), None);
+/// ```
+pub fn levenshtein_bounded<T: PartialEq>(
+    first_word: impl IntoIterator<Item = T>,
+    second_word: impl IntoIterator<Item = T>,
+    max_distance: usize,
+) -> Option<usize> {
+    let first_word: Vec<T> = first_word.into_iter().collect();
+    let second_word: Vec<T> = second_word.into_iter().collect();
+
+    let (width, height) = (first_word.len(), second_word.len());
+    if width.max(height) - width.min(height) > max_distance {
+        return None;
+    }
+    // The distance never exceeds the length of the longer word, so a larger
+    // bound, e.g. `usize::MAX` meaning no bound at all, changes nothing
+    let max_distance = max_distance.min(width.max(height));
+
+    // Every value greater than the bound is stored as `outside`, which keeps
+    // the cells that are never computed from being mistaken for good ones
+    let outside = max_distance + 1;
+    let mut previous = vec![outside; width + 1];
+    let mut current = vec![outside; width + 1];
+    for (x, cell) in previous.iter_mut().enumerate().take(outside) {
+        *cell = x;
+    }
+
+    for y in 1..=height {
+        let first_x = if y > max_distance {
+            y - max_distance
+        } else {
+            1
+        };
+        let last_x = width.min(y + max_distance);
+
+        current[first_x - 1] = if first_x == 1 {
+            y.min(outside)
+        } else {
+            outside
+        };
+        let mut row_minimum = current[first_x - 1];
+
+        for x in first_x..=last_x {
+            let the_same_letter = first_word[x - 1] == second_word[y - 1];
+            let cost = if the_same_letter { 0 } else { 1 };
+
+            let value = (previous[x - 1] + cost)
+                .min(previous[x] + 1)
+                .min(current[x - 1] + 1)
+                .min(outside);
+
+            current[x] = value;
+            row_minimum = row_minimum.min(value);
+        }
+
+        if row_minimum > max_distance {
+            return None;
+        }
+
+        mem::swap(&mut previous, &mut current);
+    }
+
+    Some(previous[width]).filter(|&distance| distance <= max_distance)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::levenshtein;
+
+    #[test]
+    fn bounded_test_within() {
+        assert_eq!(
+            levenshtein_bounded("honda".chars(), "hyundai".chars(), 3),
+            Some(3)
+        );
+        assert_eq!(
+            levenshtein_bounded("gily".chars(), "geely".chars(), 5),
+            Some(2)
+        );
+        assert_eq!(levenshtein_bounded("".chars(), "".chars(), 0), Some(0));
+    }
+
+    #[test]
+    fn bounded_test_exceeded() {
+        assert_eq!(
+            levenshtein_bounded("honda".chars(), "hyundai".chars(), 2),
+            None
+        );
+        assert_eq!(levenshtein_bounded("a".chars(), "abcd".chars(), 2), None);
+        assert_eq!(levenshtein_bounded("abc".chars(), "xyz".chars(), 0), None);
+    }
+
+    #[test]
+    fn bounded_test_unbounded() {
+        assert_eq!(
+            levenshtein_bounded("kitten".chars(), "sitting".chars(), usize::MAX),
+            Some(3)
+        );
+        assert_eq!(
+            levenshtein_bounded("".chars(), "".chars(), usize::MAX),
+            Some(0)
+        );
+    }
+
+    #[test]
+    fn bounded_test_agrees_with_levenshtein() {
+        let words = [
+            "", "a", "ab", "ba", "abc", "acb", "kitten", "sitting", "mitten",
+        ];
+        for first in words.iter() {
+            for second in words.iter() {
+                let expected = levenshtein(first.chars(), second.chars());
+                for max_distance in 0..8 {
+                    let bounded = levenshtein_bounded(first.chars(), second.chars(), max_distance);
+                    assert_eq!(bounded, Some(expected).filter(|&d| d <= max_distance));
+                }
+            }
+        }
+    }
+}
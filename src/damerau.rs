@@ -0,0 +1,321 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+
+use crate::matrix::Matrix;
+
+/// Calculate optimal string alignment distance for two words
+///
+/// This is the restricted Damerau-Levenshtein distance: besides insertions,
+/// deletions and substitutions, a swap of two adjacent elements costs 1, but
+/// no substring may be edited more than once.
+///
+/// # Arguments
+/// * `first_word` - First word
+/// * `second_word` - Second word
+///
+/// # Examples
+/// ```
+/// use edit_dist::osa_distance;
+/// assert_eq!(osa_distance("teh".chars(), "the".chars()), 1);
+/// assert_eq!(osa_distance("ca".chars(), "abc".chars()), 3);
+/// ```
+pub fn osa_distance<T: PartialEq>(
+    first_word: impl IntoIterator<Item = T>,
+    second_word: impl IntoIterator<Item = T>,
+) -> usize {
+    let first_word: Vec<T> = first_word.into_iter().collect();
+    let second_word: Vec<T> = second_word.into_iter().collect();
+
+    osa_slice(&first_word, &second_word)
+}
+
+/// Calculate optimal string alignment distance for two borrowed words
+///
+/// # Arguments
+/// * `first_word` - First word
+/// * `second_word` - Second word
+pub(crate) fn osa_slice<T: PartialEq>(first_word: &[T], second_word: &[T]) -> usize {
+    let mut matrix = Matrix::<usize>::new(first_word.len() + 1, second_word.len() + 1);
+    for y in 0..matrix.height() {
+        matrix[(y, 0)] = y;
+    }
+    for x in 0..matrix.width() {
+        matrix[(0, x)] = x;
+    }
+
+    for y in 1..matrix.height() {
+        for x in 1..matrix.width() {
+            let the_same_letter = first_word[x - 1] == second_word[y - 1];
+            let cost = if the_same_letter { 0 } else { 1 };
+
+            let mut value = (matrix[(y - 1, x - 1)] + cost)
+                .min(matrix[(y - 1, x)] + 1)
+                .min(matrix[(y, x - 1)] + 1);
+
+            let transposed = x > 1
+                && y > 1
+                && first_word[x - 1] == second_word[y - 2]
+                && first_word[x - 2] == second_word[y - 1];
+            if transposed {
+                value = value.min(matrix[(y - 2, x - 2)] + 1);
+            }
+
+            matrix[(y, x)] = value;
+        }
+    }
+
+    matrix[(matrix.height() - 1, matrix.width() - 1)]
+}
+
+/// Calculate optimal string alignment distance and the length of the shortest alignment achieving it
+///
+/// The length counts aligned columns: a transposition spans two of them,
+/// every other operation spans one.
+///
+/// # Arguments
+/// * `first_word` - First word
+/// * `second_word` - Second word
+pub(crate) fn osa_with_alignment_length<T: PartialEq>(
+    first_word: &[T],
+    second_word: &[T],
+) -> (usize, usize) {
+    // Pairs are compared lexicographically, so among the cheapest alignments
+    // the shortest one is kept
+    let mut matrix = Matrix::<(usize, usize)>::new(first_word.len() + 1, second_word.len() + 1);
+    for y in 0..matrix.height() {
+        matrix[(y, 0)] = (y, y);
+    }
+    for x in 0..matrix.width() {
+        matrix[(0, x)] = (x, x);
+    }
+
+    for y in 1..matrix.height() {
+        for x in 1..matrix.width() {
+            let the_same_letter = first_word[x - 1] == second_word[y - 1];
+            let cost = if the_same_letter { 0 } else { 1 };
+
+            let mut value = extend(matrix[(y - 1, x - 1)], cost, 1)
+                .min(extend(matrix[(y - 1, x)], 1, 1))
+                .min(extend(matrix[(y, x - 1)], 1, 1));
+
+            let transposed = x > 1
+                && y > 1
+                && first_word[x - 1] == second_word[y - 2]
+                && first_word[x - 2] == second_word[y - 1];
+            if transposed {
+                value = value.min(extend(matrix[(y - 2, x - 2)], 1, 2));
+            }
+
+            matrix[(y, x)] = value;
+        }
+    }
+
+    matrix[(matrix.height() - 1, matrix.width() - 1)]
+}
+
+/// Calculate unrestricted Damerau-Levenshtein distance for two words
+///
+/// Unlike [`osa_distance`], elements may be edited again after being
+/// transposed, which makes this a true metric. The algorithm keeps a table of
+/// the last position each element was seen at, so elements have to be hashable.
+///
+/// # Arguments
+/// * `first_word` - First word
+/// * `second_word` - Second word
+///
+/// # Examples
+/// ```
+/// use edit_dist::damerau_levenshtein;
+/// assert_eq!(damerau_levenshtein("teh".chars(), "the".chars()), 1);
+/// assert_eq!(damerau_levenshtein("ca".chars(), "abc".chars()), 2);
+/// ```
+pub fn damerau_levenshtein<T: Eq + Hash>(
+    first_word: impl IntoIterator<Item = T>,
+    second_word: impl IntoIterator<Item = T>,
+) -> usize {
+    let first_word: Vec<T> = first_word.into_iter().collect();
+    let second_word: Vec<T> = second_word.into_iter().collect();
+
+    damerau_levenshtein_slice(&first_word, &second_word)
+}
+
+/// Calculate unrestricted Damerau-Levenshtein distance for two borrowed words
+///
+/// # Arguments
+/// * `first_word` - First word
+/// * `second_word` - Second word
+pub(crate) fn damerau_levenshtein_slice<T: Eq + Hash>(
+    first_word: &[T],
+    second_word: &[T],
+) -> usize {
+    // Row and column 0 hold a sentinel larger than any reachable distance,
+    // so the cell (y + 1, x + 1) corresponds to prefixes of lengths y and x
+    let infinity = first_word.len() + second_word.len();
+    let mut matrix = Matrix::<usize>::new(first_word.len() + 2, second_word.len() + 2);
+    for y in 0..matrix.height() {
+        matrix[(y, 0)] = infinity;
+    }
+    for x in 0..matrix.width() {
+        matrix[(0, x)] = infinity;
+    }
+    for y in 1..matrix.height() {
+        matrix[(y, 1)] = y - 1;
+    }
+    for x in 1..matrix.width() {
+        matrix[(1, x)] = x - 1;
+    }
+
+    let mut last_seen_row: HashMap<&T, usize> = HashMap::new();
+
+    for y in 1..=second_word.len() {
+        let mut last_matching_x = 0;
+
+        for x in 1..=first_word.len() {
+            let last_matching_y = last_seen_row.get(&first_word[x - 1]).copied().unwrap_or(0);
+            let transposition_x = last_matching_x;
+
+            let the_same_letter = first_word[x - 1] == second_word[y - 1];
+            let cost = if the_same_letter {
+                last_matching_x = x;
+                0
+            } else {
+                1
+            };
+
+            matrix[(y + 1, x + 1)] = (matrix[(y, x)] + cost)
+                .min(matrix[(y + 1, x)] + 1)
+                .min(matrix[(y, x + 1)] + 1)
+                .min(
+                    matrix[(last_matching_y, transposition_x)]
+                        + (y - last_matching_y - 1)
+                        + 1
+                        + (x - transposition_x - 1),
+                );
+        }
+
+        last_seen_row.insert(&second_word[y - 1], y);
+    }
+
+    matrix[(matrix.height() - 1, matrix.width() - 1)]
+}
+
+/// Calculate Damerau-Levenshtein distance and the length of the shortest alignment achieving it
+///
+/// The length counts aligned columns: the transposed pair spans two of them
+/// and every element edited in between spans one.
+///
+/// # Arguments
+/// * `first_word` - First word
+/// * `second_word` - Second word
+pub(crate) fn damerau_levenshtein_with_alignment_length<T: Eq + Hash>(
+    first_word: &[T],
+    second_word: &[T],
+) -> (usize, usize) {
+    // Row and column 0 hold a sentinel larger than any reachable distance,
+    // so the cell (y + 1, x + 1) corresponds to prefixes of lengths y and x
+    let infinity = first_word.len() + second_word.len();
+    let mut matrix = Matrix::<(usize, usize)>::new(first_word.len() + 2, second_word.len() + 2);
+    for y in 0..matrix.height() {
+        matrix[(y, 0)] = (infinity, infinity);
+    }
+    for x in 0..matrix.width() {
+        matrix[(0, x)] = (infinity, infinity);
+    }
+    for y in 1..matrix.height() {
+        matrix[(y, 1)] = (y - 1, y - 1);
+    }
+    for x in 1..matrix.width() {
+        matrix[(1, x)] = (x - 1, x - 1);
+    }
+
+    let mut last_seen_row: HashMap<&T, usize> = HashMap::new();
+
+    for y in 1..=second_word.len() {
+        let mut last_matching_x = 0;
+
+        for x in 1..=first_word.len() {
+            let last_matching_y = last_seen_row.get(&first_word[x - 1]).copied().unwrap_or(0);
+            let transposition_x = last_matching_x;
+
+            let the_same_letter = first_word[x - 1] == second_word[y - 1];
+            let cost = if the_same_letter {
+                last_matching_x = x;
+                0
+            } else {
+                1
+            };
+
+            let skipped = (y - last_matching_y - 1) + (x - transposition_x - 1);
+            matrix[(y + 1, x + 1)] = extend(matrix[(y, x)], cost, 1)
+                .min(extend(matrix[(y + 1, x)], 1, 1))
+                .min(extend(matrix[(y, x + 1)], 1, 1))
+                .min(extend(
+                    matrix[(last_matching_y, transposition_x)],
+                    skipped + 1,
+                    skipped + 2,
+                ));
+        }
+
+        last_seen_row.insert(&second_word[y - 1], y);
+    }
+
+    matrix[(matrix.height() - 1, matrix.width() - 1)]
+}
+
+/// Extend an alignment by operations of given total cost spanning given number of columns
+fn extend(cell: (usize, usize), cost: usize, columns: usize) -> (usize, usize) {
+    (cell.0 + cost, cell.1 + columns)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::levenshtein;
+
+    #[test]
+    fn osa_test_transposition() {
+        assert_eq!(osa_distance("teh".chars(), "the".chars()), 1);
+        assert_eq!(levenshtein("teh".chars(), "the".chars()), 2);
+    }
+
+    #[test]
+    fn osa_test_restricted() {
+        assert_eq!(osa_distance("ca".chars(), "abc".chars()), 3);
+        assert_eq!(osa_distance("sitting".chars(), "kitten".chars()), 3);
+    }
+
+    #[test]
+    fn damerau_test_unrestricted() {
+        assert_eq!(damerau_levenshtein("ca".chars(), "abc".chars()), 2);
+        assert_eq!(damerau_levenshtein("abc".chars(), "ca".chars()), 2);
+        assert_eq!(damerau_levenshtein("sitting".chars(), "kitten".chars()), 3);
+    }
+
+    #[test]
+    fn damerau_test_empty() {
+        assert_eq!(damerau_levenshtein("".chars(), "abc".chars()), 3);
+        assert_eq!(damerau_levenshtein("abc".chars(), "".chars()), 3);
+        assert_eq!(damerau_levenshtein("".chars(), "".chars()), 0);
+    }
+
+    #[test]
+    fn damerau_test_alignment_length_keeps_distance() {
+        let words = [
+            "", "ca", "abc", "teh", "the", "sitting", "kitten", "abcdef", "badcfe",
+        ];
+        for first in words.iter() {
+            for second in words.iter() {
+                let first: Vec<char> = first.chars().collect();
+                let second: Vec<char> = second.chars().collect();
+                assert_eq!(
+                    osa_with_alignment_length(&first, &second).0,
+                    osa_slice(&first, &second)
+                );
+                assert_eq!(
+                    damerau_levenshtein_with_alignment_length(&first, &second).0,
+                    damerau_levenshtein_slice(&first, &second)
+                );
+            }
+        }
+    }
+}
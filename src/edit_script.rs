@@ -0,0 +1,425 @@
+use crate::matrix::Matrix;
+
+/// Single step of an edit script transforming the first word into the second one
+///
+/// Positions are zero-based indices into the first word (`source`) and into
+/// the second word (`target`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum EditOperation {
+    /// Elements at both positions are equal
+    Match { source: usize, target: usize },
+    /// Element of the first word is replaced by an element of the second word
+    Substitute { source: usize, target: usize },
+    /// Element of the second word is inserted
+    Insert { target: usize },
+    /// Element of the first word is deleted
+    Delete { source: usize },
+}
+
+impl EditOperation {
+    /// Get the cost of the operation in the Levenshtein sense
+    pub fn cost(&self) -> usize {
+        match self {
+            EditOperation::Match { .. } => 0,
+            _ => 1,
+        }
+    }
+
+    /// Move the operation by given offsets in both words
+    fn shifted(self, source_offset: usize, target_offset: usize) -> Self {
+        match self {
+            EditOperation::Match { source, target } => EditOperation::Match {
+                source: source + source_offset,
+                target: target + target_offset,
+            },
+            EditOperation::Substitute { source, target } => EditOperation::Substitute {
+                source: source + source_offset,
+                target: target + target_offset,
+            },
+            EditOperation::Insert { target } => EditOperation::Insert {
+                target: target + target_offset,
+            },
+            EditOperation::Delete { source } => EditOperation::Delete {
+                source: source + source_offset,
+            },
+        }
+    }
+}
+
+/// Result of aligning two words: their distance and the operations leading to it
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EditScript {
+    /// Levenshtein distance between the words
+    pub distance: usize,
+    /// Operations ordered from the beginning of both words to their ends
+    pub operations: Vec<EditOperation>,
+}
+
+/// Calculate Levenshtein distance for two words together with an optimal edit script
+///
+/// The script is recovered by walking back through the distance matrix, so
+/// the sum of [`EditOperation::cost`] over its operations always equals the
+/// returned distance.
+///
+/// # Arguments
+/// * `first_word` - First word
+/// * `second_word` - Second word
+///
+/// # Examples
+/// ```
+/// use edit_dist::{levenshtein_alignment, EditOperation};
+/// let script = levenshtein_alignment("cat".chars(), "cut".chars());
+/// assert_eq!(script.distance, 1);
+/// assert_eq!(
+///     script.operations[1],
+///     EditOperation::Substitute { source: 1, target: 1 }
+/// );
+/// ```
+pub fn levenshtein_alignment<T: PartialEq>(
+    first_word: impl IntoIterator<Item = T>,
+    second_word: impl IntoIterator<Item = T>,
+) -> EditScript {
+    let first_word: Vec<T> = first_word.into_iter().collect();
+    let second_word: Vec<T> = second_word.into_iter().collect();
+
+    let matrix = levenshtein_matrix(&first_word, &second_word);
+    EditScript {
+        distance: matrix[(matrix.height() - 1, matrix.width() - 1)],
+        operations: traceback(&matrix, &first_word, &second_word),
+    }
+}
+
+/// Calculate Levenshtein distance for two words together with an optimal edit script in linear space
+///
+/// Uses Hirschberg's divide-and-conquer algorithm: the first word is split in
+/// half and the matching split point of the second word is found from a
+/// single-column pass over the distance matrix. Memory usage is `O(n + m)`
+/// instead of the `O(n · m)` of [`levenshtein_alignment`], at the price of
+/// roughly doubling the running time. The split point is chosen where the
+/// traceback of the full matrix would cross the middle, so the script is
+/// exactly the one [`levenshtein_alignment`] returns.
+///
+/// # Arguments
+/// * `first_word` - First word
+/// * `second_word` - Second word
+///
+/// # Examples
+/// ```
+/// use edit_dist::hirschberg_alignment;
+/// let script = hirschberg_alignment("sitting".chars(), "kitten".chars());
+/// assert_eq!(script.distance, 3);
+/// assert_eq!(script.operations.len(), 7);
+/// ```
+pub fn hirschberg_alignment<T: PartialEq>(
+    first_word: impl IntoIterator<Item = T>,
+    second_word: impl IntoIterator<Item = T>,
+) -> EditScript {
+    let first_word: Vec<T> = first_word.into_iter().collect();
+    let second_word: Vec<T> = second_word.into_iter().collect();
+
+    let mut operations = Vec::with_capacity(first_word.len().max(second_word.len()));
+    hirschberg(&first_word, &second_word, 0, 0, &mut operations);
+
+    EditScript {
+        distance: operations.iter().map(EditOperation::cost).sum(),
+        operations,
+    }
+}
+
+/// Append an optimal edit script for two subwords to `operations`
+///
+/// # Arguments
+/// * `first_word` - Part of the first word
+/// * `second_word` - Part of the second word
+/// * `source_offset` - Position of `first_word` within the whole first word
+/// * `target_offset` - Position of `second_word` within the whole second word
+/// * `operations` - Script built so far
+fn hirschberg<T: PartialEq>(
+    first_word: &[T],
+    second_word: &[T],
+    source_offset: usize,
+    target_offset: usize,
+    operations: &mut Vec<EditOperation>,
+) {
+    // A matrix with at most two columns is small enough to be filled directly
+    if first_word.len() <= 1 || second_word.is_empty() {
+        let matrix = levenshtein_matrix(first_word, second_word);
+        operations.extend(
+            traceback(&matrix, first_word, second_word)
+                .into_iter()
+                .map(|operation| operation.shifted(source_offset, target_offset)),
+        );
+        return;
+    }
+
+    let middle = first_word.len() / 2;
+    let split = split_row(first_word, second_word, middle);
+
+    hirschberg(
+        &first_word[..middle],
+        &second_word[..split],
+        source_offset,
+        target_offset,
+        operations,
+    );
+    hirschberg(
+        &first_word[middle..],
+        &second_word[split..],
+        source_offset + middle,
+        target_offset + split,
+        operations,
+    );
+}
+
+/// Find the row in which the script of [`levenshtein_alignment`] leaves given column
+///
+/// The matrix is filled one column at a time, keeping only the last one. From
+/// `middle` on, every cell also remembers the lowest row in which the
+/// traceback from that cell passes through column `middle`, following the
+/// same preferences as `traceback`. The value remembered by the bottom-right
+/// cell is then the row the whole script passes `middle` in.
+///
+/// # Arguments
+/// * `first_word` - First word
+/// * `second_word` - Second word
+/// * `middle` - Column to look at, at least 1
+fn split_row<T: PartialEq>(first_word: &[T], second_word: &[T], middle: usize) -> usize {
+    let mut column: Vec<usize> = (0..=second_word.len()).collect();
+    let mut crossing = vec![0; second_word.len() + 1];
+
+    for (x, first_element) in (1..).zip(first_word) {
+        let mut diagonal = column[0];
+        let mut diagonal_crossing = crossing[0];
+        column[0] = x;
+
+        for (y, second_element) in (1..).zip(second_word) {
+            let the_same_letter = first_element == second_element;
+            let cost = if the_same_letter { 0 } else { 1 };
+            let (deletion, deletion_crossing) = (column[y], crossing[y]);
+
+            let value = (diagonal + cost).min(deletion + 1).min(column[y - 1] + 1);
+            let from_diagonal = diagonal + cost == value;
+            let from_deletion = deletion + 1 == value;
+
+            if x == middle {
+                crossing[y] = if from_diagonal || from_deletion {
+                    y
+                } else {
+                    crossing[y - 1]
+                };
+            } else if x > middle {
+                crossing[y] = if from_diagonal {
+                    diagonal_crossing
+                } else if from_deletion {
+                    deletion_crossing
+                } else {
+                    crossing[y - 1]
+                };
+            }
+
+            diagonal = deletion;
+            diagonal_crossing = deletion_crossing;
+            column[y] = value;
+        }
+    }
+
+    crossing[second_word.len()]
+}
+
+/// Fill the whole Levenshtein dynamic programming matrix for two words
+///
+/// Columns follow `first_word` and rows follow `second_word`, so the cell
+/// `(y, x)` holds the distance between the first `x` elements of `first_word`
+/// and the first `y` elements of `second_word`.
+///
+/// # Arguments
+/// * `first_word` - First word
+/// * `second_word` - Second word
+fn levenshtein_matrix<T: PartialEq>(first_word: &[T], second_word: &[T]) -> Matrix<usize> {
+    let mut matrix = Matrix::<usize>::new(first_word.len() + 1, second_word.len() + 1);
+    fill_levenshtein_matrix(&mut matrix, first_word, second_word);
+    matrix
+}
+
+/// Fill a matrix sized `(first_word.len() + 1) × (second_word.len() + 1)` like `levenshtein_matrix` does
+///
+/// # Arguments
+/// * `matrix` - Matrix to fill, its previous content is overwritten
+/// * `first_word` - First word
+/// * `second_word` - Second word
+pub(crate) fn fill_levenshtein_matrix<T: PartialEq>(
+    matrix: &mut Matrix<usize>,
+    first_word: &[T],
+    second_word: &[T],
+) {
+    for y in 0..matrix.height() {
+        matrix[(y, 0)] = y;
+    }
+    for x in 0..matrix.width() {
+        matrix[(0, x)] = x;
+    }
+
+    for y in 1..matrix.height() {
+        for x in 1..matrix.width() {
+            let the_same_letter = first_word[x - 1] == second_word[y - 1];
+            let cost = if the_same_letter { 0 } else { 1 };
+
+            matrix[(y, x)] = (matrix[(y - 1, x - 1)] + cost)
+                .min(matrix[(y - 1, x)] + 1)
+                .min(matrix[(y, x - 1)] + 1);
+        }
+    }
+}
+
+/// Walk back from the bottom-right cell of a filled Levenshtein matrix
+///
+/// Diagonal moves are preferred over deletions, and deletions over insertions,
+/// so the result is deterministic for a given pair of words.
+///
+/// # Arguments
+/// * `matrix` - Matrix filled by `levenshtein_matrix` for the same words
+/// * `first_word` - First word
+/// * `second_word` - Second word
+pub(crate) fn traceback<T: PartialEq>(
+    matrix: &Matrix<usize>,
+    first_word: &[T],
+    second_word: &[T],
+) -> Vec<EditOperation> {
+    let mut operations = Vec::new();
+    let (mut y, mut x) = (second_word.len(), first_word.len());
+
+    while y > 0 || x > 0 {
+        let current = matrix[(y, x)];
+
+        if y > 0 && x > 0 {
+            let the_same_letter = first_word[x - 1] == second_word[y - 1];
+            let cost = if the_same_letter { 0 } else { 1 };
+
+            if matrix[(y - 1, x - 1)] + cost == current {
+                operations.push(if the_same_letter {
+                    EditOperation::Match {
+                        source: x - 1,
+                        target: y - 1,
+                    }
+                } else {
+                    EditOperation::Substitute {
+                        source: x - 1,
+                        target: y - 1,
+                    }
+                });
+                y -= 1;
+                x -= 1;
+                continue;
+            }
+        }
+
+        if x > 0 && matrix[(y, x - 1)] + 1 == current {
+            operations.push(EditOperation::Delete { source: x - 1 });
+            x -= 1;
+        } else {
+            operations.push(EditOperation::Insert { target: y - 1 });
+            y -= 1;
+        }
+    }
+
+    operations.reverse();
+    operations
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn alignment_test_1() {
+        let script = levenshtein_alignment("sitting".chars(), "kitten".chars());
+        assert_eq!(script.distance, 3);
+        assert_eq!(
+            script.operations,
+            vec![
+                EditOperation::Substitute {
+                    source: 0,
+                    target: 0
+                },
+                EditOperation::Match {
+                    source: 1,
+                    target: 1
+                },
+                EditOperation::Match {
+                    source: 2,
+                    target: 2
+                },
+                EditOperation::Match {
+                    source: 3,
+                    target: 3
+                },
+                EditOperation::Substitute {
+                    source: 4,
+                    target: 4
+                },
+                EditOperation::Match {
+                    source: 5,
+                    target: 5
+                },
+                EditOperation::Delete { source: 6 },
+            ]
+        );
+    }
+
+    #[test]
+    fn alignment_test_2() {
+        let script = levenshtein_alignment("honda".chars(), "hyundai".chars());
+        let cost: usize = script.operations.iter().map(EditOperation::cost).sum();
+        assert_eq!(script.distance, 3);
+        assert_eq!(cost, 3);
+    }
+
+    #[test]
+    fn hirschberg_test_matches_full_matrix() {
+        let words = [
+            "", "a", "ab", "ba", "honda", "hyundai", "kitten", "sitting", "gily", "geely",
+        ];
+        for first in words.iter() {
+            for second in words.iter() {
+                let script = hirschberg_alignment(first.chars(), second.chars());
+                let expected = levenshtein_alignment(first.chars(), second.chars());
+                assert_eq!(script, expected);
+            }
+        }
+    }
+
+    #[test]
+    fn hirschberg_test_all_short_words() {
+        // Every word over a two-letter alphabet with at most five letters
+        let words: Vec<Vec<char>> = (0..=5)
+            .flat_map(|length| {
+                (0..1 << length).map(move |bits: u32| {
+                    (0..length)
+                        .map(|i| if bits >> i & 1 == 1 { 'b' } else { 'a' })
+                        .collect()
+                })
+            })
+            .collect();
+
+        for first in &words {
+            for second in &words {
+                let script = hirschberg_alignment(first.iter(), second.iter());
+                let expected = levenshtein_alignment(first.iter(), second.iter());
+                assert_eq!(script, expected);
+            }
+        }
+    }
+
+    #[test]
+    fn alignment_test_empty() {
+        let script = levenshtein_alignment("".chars(), "ab".chars());
+        assert_eq!(script.distance, 2);
+        assert_eq!(
+            script.operations,
+            vec![
+                EditOperation::Insert { target: 0 },
+                EditOperation::Insert { target: 1 },
+            ]
+        );
+    }
+}
@@ -0,0 +1,67 @@
+use unicode_segmentation::UnicodeSegmentation;
+
+use crate::levenshtein_bit_parallel;
+
+/// Split a string into extended grapheme clusters
+///
+/// The result can be passed to any generic function of the crate, so that
+/// user-perceived characters are compared instead of Unicode scalar values.
+///
+/// # Arguments
+/// * `word` - String to split
+///
+/// # Examples
+/// ```
+/// use edit_dist::{graphemes, osa_distance};
+/// assert_eq!(graphemes("cafe\u{301}").count(), 4);
+/// assert_eq!(osa_distance(graphemes("Việt"), graphemes("Viêt")), 1);
+/// ```
+pub fn graphemes(word: &str) -> impl Iterator<Item = &str> {
+    word.graphemes(true)
+}
+
+/// Calculate Levenshtein distance for two strings split into extended grapheme clusters
+///
+/// A letter with combining marks or an emoji ZWJ sequence counts as a single
+/// element, so replacing it costs 1 no matter how many scalar values it has.
+///
+/// # Arguments
+/// * `first_word` - First word
+/// * `second_word` - Second word
+///
+/// # Examples
+/// ```
+/// use edit_dist::{levenshtein_graphemes, levenshtein_str};
+/// // "e" followed by a combining acute accent
+/// assert_eq!(levenshtein_graphemes("cafe\u{301}", "cafe"), 1);
+/// assert_eq!(levenshtein_str("cafe\u{301}", "cafe"), 1);
+/// assert_eq!(levenshtein_graphemes("cafe\u{301}", "cafa"), 1);
+/// assert_eq!(levenshtein_str("cafe\u{301}", "cafa"), 2);
+/// ```
+pub fn levenshtein_graphemes(first_word: &str, second_word: &str) -> usize {
+    levenshtein_bit_parallel(graphemes(first_word), graphemes(second_word))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::levenshtein_str;
+
+    #[test]
+    fn graphemes_test_combining_marks() {
+        // Hindi syllables made of a consonant and a vowel sign
+        assert_eq!(levenshtein_graphemes("नमस्ते", "नमस्ते"), 0);
+        assert_eq!(levenshtein_graphemes("कि", "का"), 1);
+        assert_eq!(levenshtein_str("कि", "का"), 1);
+        assert_eq!(levenshtein_graphemes("कि", "क"), 1);
+    }
+
+    #[test]
+    fn graphemes_test_emoji() {
+        let family = "\u{1F468}\u{200D}\u{1F469}\u{200D}\u{1F467}";
+        let first = format!("a{}b", family);
+        assert_eq!(levenshtein_graphemes(&first, "ab"), 1);
+        assert_eq!(levenshtein_str(&first, "ab"), 5);
+        assert_eq!(levenshtein_graphemes(&first, "axb"), 1);
+    }
+}
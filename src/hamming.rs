@@ -0,0 +1,195 @@
+use std::convert::TryInto;
+use std::error::Error;
+use std::fmt;
+
+/// Error returned when the Hamming distance is undefined
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HammingError {
+    /// Words have different lengths
+    LengthMismatch {
+        first_length: usize,
+        second_length: usize,
+    },
+}
+
+impl fmt::Display for HammingError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            HammingError::LengthMismatch {
+                first_length,
+                second_length,
+            } => write!(
+                f,
+                "Hamming distance requires words of equal length, got {} and {}",
+                first_length, second_length
+            ),
+        }
+    }
+}
+
+impl Error for HammingError {}
+
+/// Calculate Hamming distance for two words of equal length
+///
+/// # Arguments
+/// * `first_word` - First word
+/// * `second_word` - Second word
+///
+/// # Examples
+/// ```
+/// use edit_dist::{hamming, HammingError};
+/// assert_eq!(hamming("karolin".chars(), "kathrin".chars()), Ok(3));
+/// assert_eq!(
+///     hamming("abc".chars(), "ab".chars()),
+///     Err(HammingError::LengthMismatch { first_length: 3, second_length: 2 })
+/// );
+/// ```
+pub fn hamming<T: PartialEq>(
+    first_word: impl IntoIterator<Item = T>,
+    second_word: impl IntoIterator<Item = T>,
+) -> Result<usize, HammingError> {
+    let mut first_word = first_word.into_iter().fuse();
+    let mut second_word = second_word.into_iter().fuse();
+    let mut length = 0;
+    let mut distance = 0;
+
+    loop {
+        match (first_word.next(), second_word.next()) {
+            (Some(first), Some(second)) => {
+                if first != second {
+                    distance += 1;
+                }
+                length += 1;
+            }
+            (None, None) => return Ok(distance),
+            (first, second) => {
+                return Err(HammingError::LengthMismatch {
+                    first_length: length + first.map_or(0, |_| 1 + first_word.count()),
+                    second_length: length + second.map_or(0, |_| 1 + second_word.count()),
+                })
+            }
+        }
+    }
+}
+
+/// Calculate Hamming distance for two byte slices of equal length
+///
+/// Bytes are compared eight at a time, packed into machine words.
+///
+/// # Arguments
+/// * `first_word` - First word
+/// * `second_word` - Second word
+///
+/// # Examples
+/// ```
+/// use edit_dist::hamming_bytes;
+/// assert_eq!(hamming_bytes(b"ACGTACGTAC", b"ACCTACGTAA"), Ok(2));
+/// ```
+pub fn hamming_bytes(first_word: &[u8], second_word: &[u8]) -> Result<usize, HammingError> {
+    const LOW_BITS: u64 = 0x0101_0101_0101_0101;
+
+    check_lengths(first_word.len(), second_word.len())?;
+
+    let first_chunks = first_word.chunks_exact(8);
+    let second_chunks = second_word.chunks_exact(8);
+    let tail = first_chunks
+        .remainder()
+        .iter()
+        .zip(second_chunks.remainder())
+        .filter(|(first, second)| first != second)
+        .count();
+
+    let packed: usize = first_chunks
+        .zip(second_chunks)
+        .map(|(first, second)| {
+            let mut difference = u64::from_ne_bytes(first.try_into().unwrap())
+                ^ u64::from_ne_bytes(second.try_into().unwrap());
+            // Fold every differing byte into its lowest bit
+            difference |= difference >> 4;
+            difference |= difference >> 2;
+            difference |= difference >> 1;
+            (difference & LOW_BITS).count_ones() as usize
+        })
+        .sum();
+
+    Ok(packed + tail)
+}
+
+/// Calculate bitwise Hamming distance for two bit vectors packed into `u64` words
+///
+/// # Arguments
+/// * `first_bits` - First bit vector
+/// * `second_bits` - Second bit vector
+///
+/// # Examples
+/// ```
+/// use edit_dist::hamming_bits;
+/// assert_eq!(hamming_bits(&[0b1011, 0], &[0b0001, 1]), Ok(3));
+/// ```
+pub fn hamming_bits(first_bits: &[u64], second_bits: &[u64]) -> Result<usize, HammingError> {
+    check_lengths(first_bits.len(), second_bits.len())?;
+
+    Ok(first_bits
+        .iter()
+        .zip(second_bits)
+        .map(|(first, second)| (first ^ second).count_ones() as usize)
+        .sum())
+}
+
+/// Make sure both words have the same length
+fn check_lengths(first_length: usize, second_length: usize) -> Result<(), HammingError> {
+    if first_length == second_length {
+        Ok(())
+    } else {
+        Err(HammingError::LengthMismatch {
+            first_length,
+            second_length,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn hamming_test_equal_lengths() {
+        assert_eq!(hamming("karolin".chars(), "kerstin".chars()), Ok(3));
+        assert_eq!(hamming("".chars(), "".chars()), Ok(0));
+    }
+
+    #[test]
+    fn hamming_test_length_mismatch() {
+        assert_eq!(
+            hamming("ab".chars(), "abcd".chars()),
+            Err(HammingError::LengthMismatch {
+                first_length: 2,
+                second_length: 4
+            })
+        );
+        assert_eq!(
+            hamming_bytes(b"abcd", b"a"),
+            Err(HammingError::LengthMismatch {
+                first_length: 4,
+                second_length: 1
+            })
+        );
+    }
+
+    #[test]
+    fn hamming_bytes_test_agrees_with_hamming() {
+        let first = b"the quick brown fox jumps over the lazy dog";
+        let second = b"the quack brown fax jumps ovr  the lazy cog";
+        assert_eq!(
+            hamming_bytes(first, second),
+            hamming(first.iter(), second.iter())
+        );
+        assert_eq!(hamming_bytes(first, second), Ok(5));
+    }
+
+    #[test]
+    fn hamming_bits_test() {
+        assert_eq!(hamming_bits(&[!0, 0], &[0, !0]), Ok(128));
+        assert_eq!(hamming_bits(&[], &[]), Ok(0));
+    }
+}
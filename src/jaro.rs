@@ -0,0 +1,202 @@
+/// Default weight of the common prefix in Jaro-Winkler similarity
+pub const DEFAULT_PREFIX_SCALE: f64 = 0.1;
+
+/// Default number of leading elements taken into account by Jaro-Winkler similarity
+pub const DEFAULT_MAX_PREFIX_LENGTH: usize = 4;
+
+/// Calculate Jaro similarity for two words
+///
+/// The result is in range [0, 1], where 1 means equal words and 0 means
+/// words without any matching elements.
+///
+/// # Arguments
+/// * `first_word` - First word
+/// * `second_word` - Second word
+///
+/// # Examples
+/// ```
+/// use edit_dist::jaro;
+/// let similarity = jaro("martha".chars(), "marhta".chars());
+/// assert!((similarity - 0.944).abs() < 0.001);
+/// ```
+pub fn jaro<T: PartialEq>(
+    first_word: impl IntoIterator<Item = T>,
+    second_word: impl IntoIterator<Item = T>,
+) -> f64 {
+    let first_word: Vec<T> = first_word.into_iter().collect();
+    let second_word: Vec<T> = second_word.into_iter().collect();
+
+    jaro_slices(&first_word, &second_word)
+}
+
+/// Calculate Jaro-Winkler similarity for two words with the default parameters
+///
+/// The common prefix of up to 4 elements is weighted with a scale of 0.1.
+///
+/// # Arguments
+/// * `first_word` - First word
+/// * `second_word` - Second word
+///
+/// # Examples
+/// ```
+/// use edit_dist::jaro_winkler;
+/// let similarity = jaro_winkler("martha".chars(), "marhta".chars());
+/// assert!((similarity - 0.961).abs() < 0.001);
+/// ```
+pub fn jaro_winkler<T: PartialEq>(
+    first_word: impl IntoIterator<Item = T>,
+    second_word: impl IntoIterator<Item = T>,
+) -> f64 {
+    jaro_winkler_with(
+        first_word,
+        second_word,
+        DEFAULT_PREFIX_SCALE,
+        DEFAULT_MAX_PREFIX_LENGTH,
+    )
+}
+
+/// Calculate Jaro-Winkler similarity for two words
+///
+/// # Arguments
+/// * `first_word` - First word
+/// * `second_word` - Second word
+/// * `prefix_scale` - Weight of every element of the common prefix
+/// * `max_prefix_length` - Maximal length of the common prefix taken into account
+///
+/// # Panics
+/// Panics if `prefix_scale` is negative or `prefix_scale * max_prefix_length`
+/// exceeds 1, as the similarity could leave range [0, 1] then.
+///
+/// # Examples
+/// ```
+/// use edit_dist::jaro_winkler_with;
+/// let similarity = jaro_winkler_with("dixon".chars(), "dicksonx".chars(), 0.2, 3);
+/// assert!((similarity - 0.86).abs() < 0.001);
+/// ```
+pub fn jaro_winkler_with<T: PartialEq>(
+    first_word: impl IntoIterator<Item = T>,
+    second_word: impl IntoIterator<Item = T>,
+    prefix_scale: f64,
+    max_prefix_length: usize,
+) -> f64 {
+    assert!(
+        prefix_scale >= 0.0 && prefix_scale * max_prefix_length as f64 <= 1.0,
+        "Invalid prefix scale {} for prefix length {}",
+        prefix_scale,
+        max_prefix_length
+    );
+
+    let first_word: Vec<T> = first_word.into_iter().collect();
+    let second_word: Vec<T> = second_word.into_iter().collect();
+
+    let similarity = jaro_slices(&first_word, &second_word);
+    let prefix_length = first_word
+        .iter()
+        .zip(second_word.iter())
+        .take(max_prefix_length)
+        .take_while(|(first, second)| first == second)
+        .count();
+
+    similarity + prefix_length as f64 * prefix_scale * (1.0 - similarity)
+}
+
+/// Calculate Jaro similarity for two collected words
+///
+/// # Arguments
+/// * `first_word` - First word
+/// * `second_word` - Second word
+fn jaro_slices<T: PartialEq>(first_word: &[T], second_word: &[T]) -> f64 {
+    if first_word.is_empty() && second_word.is_empty() {
+        return 1.0;
+    }
+    if first_word.is_empty() || second_word.is_empty() {
+        return 0.0;
+    }
+
+    // Elements match only if they are not farther apart than this
+    let window = (first_word.len().max(second_word.len()) / 2).saturating_sub(1);
+
+    let mut first_matched = vec![false; first_word.len()];
+    let mut second_matched = vec![false; second_word.len()];
+    let mut matches = 0;
+
+    for (i, first) in first_word.iter().enumerate() {
+        let start = i.saturating_sub(window);
+        let end = second_word.len().min(i + window + 1);
+
+        for j in start..end {
+            if !second_matched[j] && *first == second_word[j] {
+                first_matched[i] = true;
+                second_matched[j] = true;
+                matches += 1;
+                break;
+            }
+        }
+    }
+
+    if matches == 0 {
+        return 0.0;
+    }
+
+    let first_matches = first_word
+        .iter()
+        .zip(first_matched)
+        .filter_map(|(element, matched)| if matched { Some(element) } else { None });
+    let second_matches = second_word
+        .iter()
+        .zip(second_matched)
+        .filter_map(|(element, matched)| if matched { Some(element) } else { None });
+    let half_transpositions = first_matches
+        .zip(second_matches)
+        .filter(|(first, second)| first != second)
+        .count();
+
+    let matches = matches as f64;
+    let transpositions = (half_transpositions / 2) as f64;
+
+    (matches / first_word.len() as f64
+        + matches / second_word.len() as f64
+        + (matches - transpositions) / matches)
+        / 3.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_close(actual: f64, expected: f64) {
+        assert!(
+            (actual - expected).abs() < 0.001,
+            "{} is not close to {}",
+            actual,
+            expected
+        );
+    }
+
+    #[test]
+    fn jaro_test() {
+        assert_close(jaro("dixon".chars(), "dicksonx".chars()), 0.767);
+        assert_close(jaro("dwayne".chars(), "duane".chars()), 0.822);
+        assert_close(jaro("abc".chars(), "xyz".chars()), 0.0);
+    }
+
+    #[test]
+    fn jaro_test_empty() {
+        assert_close(jaro("".chars(), "".chars()), 1.0);
+        assert_close(jaro("abc".chars(), "".chars()), 0.0);
+        assert_close(jaro_winkler("".chars(), "abc".chars()), 0.0);
+    }
+
+    #[test]
+    fn jaro_winkler_test() {
+        assert_close(jaro_winkler("dwayne".chars(), "duane".chars()), 0.84);
+        assert_close(jaro_winkler("dixon".chars(), "dicksonx".chars()), 0.813);
+        assert_close(jaro_winkler("same".chars(), "same".chars()), 1.0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn jaro_winkler_test_invalid_scale() {
+        jaro_winkler_with("abc".chars(), "abd".chars(), 0.5, 4);
+    }
+}
@@ -1,52 +1,411 @@
+mod alignment;
+mod automaton;
+mod bit_parallel;
+mod bk_tree;
+mod bounded;
+mod damerau;
+mod edit_script;
+#[cfg(feature = "graphemes")]
+mod graphemes;
+mod hamming;
+mod jaro;
 mod matrix;
+mod metric;
+mod normalized;
+mod scratch;
+mod search;
+#[cfg(feature = "normalization")]
+mod string_options;
+mod symspell;
+mod weighted;
 
-use matrix::Matrix;
+pub use alignment::{
+    affine_alignment, needleman_wunsch, smith_waterman, smith_waterman_top, AffineAlignment,
+    AffineCosts, GlobalAlignment, LocalAlignment, ParseMatrixError, SubstitutionMatrix,
+    SubstitutionScore,
+};
+pub use automaton::LevenshteinAutomaton;
+pub use bit_parallel::levenshtein_bit_parallel;
+pub use bk_tree::BkTree;
+pub use bounded::levenshtein_bounded;
+pub use damerau::{damerau_levenshtein, osa_distance};
+pub use edit_script::{hirschberg_alignment, levenshtein_alignment, EditOperation, EditScript};
+#[cfg(feature = "graphemes")]
+pub use graphemes::{graphemes, levenshtein_graphemes};
+pub use hamming::{hamming, hamming_bits, hamming_bytes, HammingError};
+pub use jaro::{
+    jaro, jaro_winkler, jaro_winkler_with, DEFAULT_MAX_PREFIX_LENGTH, DEFAULT_PREFIX_SCALE,
+};
+pub use matrix::{Matrix, MatrixError, Selector};
+pub use metric::{
+    DamerauLevenshtein, Indel, Jaro, JaroWinkler, Levenshtein, Metric, OptimalStringAlignment,
+    RawDistance,
+};
+pub use normalized::{
+    damerau_levenshtein_similarity, hamming_similarity, indel_similarity, levenshtein_similarity,
+    normalized_damerau_levenshtein, normalized_hamming, normalized_indel_distance,
+    normalized_levenshtein, normalized_osa_distance, osa_similarity, Normalization,
+};
+pub use scratch::LevenshteinScratch;
+pub use search::{find_match_ends, find_matches, Match};
+#[cfg(all(feature = "normalization", feature = "graphemes"))]
+pub use string_options::levenshtein_graphemes_with;
+#[cfg(feature = "normalization")]
+pub use string_options::{levenshtein_str_with, NormalizationForm, StringOptions};
+pub use symspell::{Suggestion, SymSpell};
+pub use weighted::{weighted_levenshtein, Cost, CostModel, UnitCost};
 
 /// Calculate Levenshtein distance for two words
 ///
+/// Elements only need to be comparable, so this runs the `O(n · m)` dynamic
+/// programming algorithm. The bit-parallel algorithm needs a table of
+/// elements, which requires `Eq + Hash`, and without specialization a generic
+/// function cannot switch to it based on the element type. For hashable
+/// elements call [`levenshtein_bit_parallel`], [`levenshtein_str`] or
+/// [`levenshtein_bytes`] instead, or use the [`Levenshtein`] metric, which
+/// always takes the bit-parallel path.
+///
 /// # Arguments
 /// * `first_word` - First word
 /// * `second_word` - Second word
 ///
 /// # Examples
 /// ```
-/// use edit_distance::levenshtein;
+/// use edit_dist::levenshtein;
 /// let dist = levenshtein(
-///     "lorem".char(),
-///     "ipsum".char()
+///     "lorem".chars(),
+///     "ipsum".chars()
 /// );
+/// assert_eq!(dist, 4);
 /// ```
 pub fn levenshtein<T: PartialEq>(
-    first_word: impl Iterator<Item = T>,
-    second_word: impl Iterator<Item = T>,
+    first_word: impl IntoIterator<Item = T>,
+    second_word: impl IntoIterator<Item = T>,
 ) -> usize {
-    let first_word: Vec<T> = first_word.collect();
-    let second_word: Vec<T> = second_word.collect();
+    let first_word: Vec<T> = first_word.into_iter().collect();
+    let second_word: Vec<T> = second_word.into_iter().collect();
 
-    let mut matrix = Matrix::<usize>::new(first_word.len() + 1, second_word.len() + 1);
-    for y in 0..matrix.height() {
-        matrix[(y, 0)] = y;
+    levenshtein_slice(&first_word, &second_word)
+}
+
+/// Calculate Levenshtein distance for two borrowed words
+///
+/// Unlike [`levenshtein`], neither word is collected nor copied.
+///
+/// # Arguments
+/// * `first_word` - First word
+/// * `second_word` - Second word
+///
+/// # Examples
+/// ```
+/// use edit_dist::levenshtein_slice;
+/// let dist = levenshtein_slice(&[1, 2, 3, 4], &[1, 3, 4, 5]);
+/// assert_eq!(dist, 2);
+/// ```
+pub fn levenshtein_slice<T: PartialEq>(first_word: &[T], second_word: &[T]) -> usize {
+    levenshtein_slice_by(first_word, second_word, |first, second| first == second)
+}
+
+/// Calculate Levenshtein distance for two words, comparing elements with a closure
+///
+/// The words may even consist of elements of different types.
+///
+/// # Arguments
+/// * `first_word` - First word
+/// * `second_word` - Second word
+/// * `equal` - Function deciding whether two elements are equal
+///
+/// # Examples
+/// ```
+/// use edit_dist::levenshtein_by;
+/// let dist = levenshtein_by(
+///     vec![1.0, 2.0, 3.0],
+///     vec![1.05, 2.4, 2.95],
+///     |first: &f64, second: &f64| (first - second).abs() < 0.1
+/// );
+/// assert_eq!(dist, 1);
+/// ```
+pub fn levenshtein_by<A, B>(
+    first_word: impl IntoIterator<Item = A>,
+    second_word: impl IntoIterator<Item = B>,
+    equal: impl FnMut(&A, &B) -> bool,
+) -> usize {
+    let first_word: Vec<A> = first_word.into_iter().collect();
+    let second_word: Vec<B> = second_word.into_iter().collect();
+
+    levenshtein_slice_by(&first_word, &second_word, equal)
+}
+
+/// Calculate Levenshtein distance for two words, comparing keys extracted from elements
+///
+/// The key is extracted exactly once per element.
+///
+/// # Arguments
+/// * `first_word` - First word
+/// * `second_word` - Second word
+/// * `key` - Function extracting the compared key from an element
+///
+/// # Examples
+/// ```
+/// use edit_dist::levenshtein_by_key;
+/// let dist = levenshtein_by_key(
+///     "Hello".chars(),
+///     "hELLO!".chars(),
+///     |c| c.to_ascii_lowercase()
+/// );
+/// assert_eq!(dist, 1);
+/// ```
+pub fn levenshtein_by_key<T, K: PartialEq>(
+    first_word: impl IntoIterator<Item = T>,
+    second_word: impl IntoIterator<Item = T>,
+    mut key: impl FnMut(&T) -> K,
+) -> usize {
+    let first_keys: Vec<K> = first_word
+        .into_iter()
+        .map(|element| key(&element))
+        .collect();
+    let second_keys: Vec<K> = second_word
+        .into_iter()
+        .map(|element| key(&element))
+        .collect();
+
+    levenshtein_slice(&first_keys, &second_keys)
+}
+
+/// Calculate Levenshtein distance for two borrowed words, comparing elements with a closure
+///
+/// # Arguments
+/// * `first_word` - First word
+/// * `second_word` - Second word
+/// * `equal` - Function deciding whether two elements are equal
+pub fn levenshtein_slice_by<A, B>(
+    first_word: &[A],
+    second_word: &[B],
+    equal: impl FnMut(&A, &B) -> bool,
+) -> usize {
+    levenshtein_with_row(&mut Vec::new(), first_word, second_word, equal)
+}
+
+/// Calculate Levenshtein distance for two borrowed words, reusing a buffer for the row of the distance matrix
+///
+/// # Arguments
+/// * `row` - Buffer for the row, its previous content is discarded
+/// * `first_word` - First word
+/// * `second_word` - Second word
+/// * `equal` - Function deciding whether two elements are equal
+pub(crate) fn levenshtein_with_row<A, B>(
+    row: &mut Vec<usize>,
+    first_word: &[A],
+    second_word: &[B],
+    mut equal: impl FnMut(&A, &B) -> bool,
+) -> usize {
+    // Only a single row of the distance matrix is kept, laid along the shorter word
+    if first_word.len() <= second_word.len() {
+        single_row(row, first_word, second_word, equal)
+    } else {
+        single_row(row, second_word, first_word, |second, first| {
+            equal(first, second)
+        })
     }
-    for x in 0..matrix.width() {
-        matrix[(0, x)] = x;
+}
+
+/// Calculate Levenshtein distance keeping a single row of the distance matrix
+///
+/// # Arguments
+/// * `row` - Buffer for the row, its previous content is discarded
+/// * `columns` - Word laid along the row
+/// * `rows` - The other word
+/// * `equal` - Function deciding whether two elements are equal
+fn single_row<C, R>(
+    row: &mut Vec<usize>,
+    columns: &[C],
+    rows: &[R],
+    mut equal: impl FnMut(&C, &R) -> bool,
+) -> usize {
+    row.clear();
+    row.extend(0..=columns.len());
+
+    for (y, row_element) in rows.iter().enumerate() {
+        let mut diagonal = row[0];
+        row[0] = y + 1;
+
+        for x in 1..row.len() {
+            let the_same_letter = equal(&columns[x - 1], row_element);
+            let cost = if the_same_letter { 0 } else { 1 };
+
+            let value = (diagonal + cost).min(row[x] + 1).min(row[x - 1] + 1);
+            diagonal = row[x];
+            row[x] = value;
+        }
     }
 
+    row[columns.len()]
+}
+
+/// Calculate Levenshtein distance for two strings
+///
+/// Strings are compared by Unicode scalar values (`char`s). The bit-parallel
+/// algorithm is used and neither string is copied.
+///
+/// # Arguments
+/// * `first_word` - First word
+/// * `second_word` - Second word
+///
+/// # Examples
+/// ```
+/// use edit_dist::levenshtein_str;
+/// assert_eq!(levenshtein_str("żółw", "żółty"), 2);
+/// ```
+pub fn levenshtein_str(first_word: &str, second_word: &str) -> usize {
+    bit_parallel::bit_parallel_str(first_word, second_word)
+}
+
+/// Calculate Levenshtein distance for two byte slices
+///
+/// The bit-parallel algorithm is used with match masks indexed directly by
+/// byte values, which makes this the fastest entry point of the crate.
+///
+/// # Arguments
+/// * `first_word` - First word
+/// * `second_word` - Second word
+///
+/// # Examples
+/// ```
+/// use edit_dist::levenshtein_bytes;
+/// assert_eq!(levenshtein_bytes(b"sitting", b"kitten"), 3);
+/// ```
+pub fn levenshtein_bytes(first_word: &[u8], second_word: &[u8]) -> usize {
+    bit_parallel::bit_parallel_bytes(first_word, second_word)
+}
+
+/// Calculate length of the longest common subsequence of two words
+///
+/// # Arguments
+/// * `first_word` - First word
+/// * `second_word` - Second word
+///
+/// # Examples
+/// ```
+/// use edit_dist::lcs_length;
+/// let length = lcs_length(
+///     "sitting".chars(),
+///     "kitten".chars()
+/// );
+/// assert_eq!(length, 4);
+/// ```
+pub fn lcs_length<T: PartialEq>(
+    first_word: impl IntoIterator<Item = T>,
+    second_word: impl IntoIterator<Item = T>,
+) -> usize {
+    let first_word: Vec<T> = first_word.into_iter().collect();
+    let second_word: Vec<T> = second_word.into_iter().collect();
+
+    let (columns, rows) = if first_word.len() <= second_word.len() {
+        (&first_word, &second_word)
+    } else {
+        (&second_word, &first_word)
+    };
+
+    let mut row = vec![0; columns.len() + 1];
+    for row_element in rows.iter() {
+        let mut diagonal = row[0];
+
+        for x in 1..row.len() {
+            let value = if columns[x - 1] == *row_element {
+                diagonal + 1
+            } else {
+                row[x].max(row[x - 1])
+            };
+            diagonal = row[x];
+            row[x] = value;
+        }
+    }
+
+    row[columns.len()]
+}
+
+/// Calculate indel distance for two words
+///
+/// Only insertions and deletions are allowed, so a substitution costs 2.
+///
+/// # Arguments
+/// * `first_word` - First word
+/// * `second_word` - Second word
+///
+/// # Examples
+/// ```
+/// use edit_dist::indel_distance;
+/// let dist = indel_distance(
+///     "sitting".chars(),
+///     "kitten".chars()
+/// );
+/// assert_eq!(dist, 5);
+/// ```
+pub fn indel_distance<T: PartialEq>(
+    first_word: impl IntoIterator<Item = T>,
+    second_word: impl IntoIterator<Item = T>,
+) -> usize {
+    let first_word: Vec<T> = first_word.into_iter().collect();
+    let second_word: Vec<T> = second_word.into_iter().collect();
+
+    let common = lcs_length(first_word.iter(), second_word.iter());
+    first_word.len() + second_word.len() - 2 * common
+}
+
+/// Find the longest common subsequence of two words
+///
+/// The returned elements are taken from the first word.
+///
+/// # Arguments
+/// * `first_word` - First word
+/// * `second_word` - Second word
+///
+/// # Examples
+/// ```
+/// use edit_dist::longest_common_subsequence;
+/// let subsequence: String = longest_common_subsequence(
+///     "sitting".chars(),
+///     "kitten".chars()
+/// ).into_iter().collect();
+/// assert_eq!(subsequence, "ittn");
+/// ```
+pub fn longest_common_subsequence<T: PartialEq>(
+    first_word: impl IntoIterator<Item = T>,
+    second_word: impl IntoIterator<Item = T>,
+) -> Vec<T> {
+    let first_word: Vec<T> = first_word.into_iter().collect();
+    let second_word: Vec<T> = second_word.into_iter().collect();
+
+    let mut matrix = Matrix::<usize>::new(first_word.len() + 1, second_word.len() + 1);
     for y in 1..matrix.height() {
         for x in 1..matrix.width() {
-            let the_same_letter = first_word[x - 1] == second_word[y - 1];
-            let cost = if the_same_letter { 0 } else { 1 };
-
-            let values: Vec<usize> = vec![
-                matrix[(y - 1, x - 1)] + cost,
-                matrix[(y - 1, x)] + 1,
-                matrix[(y, x - 1)] + 1,
-            ];
+            matrix[(y, x)] = if first_word[x - 1] == second_word[y - 1] {
+                matrix[(y - 1, x - 1)] + 1
+            } else {
+                matrix[(y - 1, x)].max(matrix[(y, x - 1)])
+            };
+        }
+    }
 
-            matrix[(y, x)] = values.into_iter().min().unwrap();
+    let mut in_subsequence = vec![false; first_word.len()];
+    let (mut y, mut x) = (second_word.len(), first_word.len());
+    while y > 0 && x > 0 {
+        if first_word[x - 1] == second_word[y - 1] {
+            in_subsequence[x - 1] = true;
+            y -= 1;
+            x -= 1;
+        } else if matrix[(y, x - 1)] >= matrix[(y - 1, x)] {
+            x -= 1;
+        } else {
+            y -= 1;
         }
     }
 
-    matrix[(matrix.height() - 1, matrix.width() - 1)]
+    first_word
+        .into_iter()
+        .zip(in_subsequence)
+        .filter_map(|(element, selected)| if selected { Some(element) } else { None })
+        .collect()
 }
 
 #[cfg(test)]
@@ -73,4 +432,72 @@ mod tests {
         let b = "geely".chars();
         assert_eq!(levenshtein(a, b), 2);
     }
+
+    #[test]
+    fn distance_test_shorter_first() {
+        assert_eq!(levenshtein("ab".chars(), "cabd".chars()), 2);
+        assert_eq!(levenshtein("cabd".chars(), "ab".chars()), 2);
+        assert_eq!(levenshtein("".chars(), "abc".chars()), 3);
+    }
+
+    #[test]
+    fn lcs_test() {
+        assert_eq!(lcs_length("ABCBDAB".chars(), "BDCABA".chars()), 4);
+        assert_eq!(lcs_length("".chars(), "abc".chars()), 0);
+
+        let subsequence = longest_common_subsequence("ABCBDAB".chars(), "BDCABA".chars());
+        assert_eq!(subsequence.len(), 4);
+        assert_eq!(
+            lcs_length(
+                subsequence.iter(),
+                "ABCBDAB".chars().collect::<Vec<_>>().iter()
+            ),
+            4
+        );
+        assert_eq!(
+            lcs_length(
+                subsequence.iter(),
+                "BDCABA".chars().collect::<Vec<_>>().iter()
+            ),
+            4
+        );
+    }
+
+    #[test]
+    fn indel_test() {
+        assert_eq!(indel_distance("honda".chars(), "hyundai".chars()), 4);
+        assert_eq!(indel_distance("abc".chars(), "abd".chars()), 2);
+        assert_eq!(indel_distance("".chars(), "".chars()), 0);
+    }
+
+    #[test]
+    fn distance_test_borrowed() {
+        let a = vec![String::from("lorem"), String::from("ipsum")];
+        let b = vec![String::from("ipsum")];
+        assert_eq!(levenshtein_slice(&a, &b), 1);
+        assert_eq!(levenshtein(&a, &b), 1);
+        assert_eq!(levenshtein_str("honda", "hyundai"), 3);
+        assert_eq!(levenshtein_bytes(b"gily", b"geely"), 2);
+    }
+
+    #[test]
+    fn distance_test_custom_equality() {
+        let a = ["let", "x", "=", "1"];
+        let b = ["let", "y", "=", "2", ";"];
+        let kind = |token: &&str| token.chars().next().map(|c| c.is_alphanumeric());
+        assert_eq!(
+            levenshtein_by_key(a.iter(), b.iter(), |token| kind(token)),
+            1
+        );
+
+        let lengths = [3, 1, 1, 1];
+        let dist = levenshtein_by(lengths.iter(), b.iter(), |length, token| {
+            **length == token.len()
+        });
+        assert_eq!(dist, 1);
+        let dist = levenshtein_by(b.iter(), lengths.iter(), |token, length| {
+            **length == token.len()
+        });
+        assert_eq!(dist, 1);
+    }
 }
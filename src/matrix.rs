@@ -1,10 +1,41 @@
+use std::error::Error;
 use std::fmt;
 use std::ops::{Index, IndexMut};
 
-/// Type being used for selecting cells from a matrix
+/// Type being used for selecting cells from a matrix, ordered as `(y, x)`
 pub type Selector = (usize, usize);
 
+/// Error returned by the checked accessors of a matrix
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MatrixError {
+    /// Selector points outside of the matrix
+    OutOfBounds {
+        selector: Selector,
+        width: usize,
+        height: usize,
+    },
+}
+
+impl fmt::Display for MatrixError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            MatrixError::OutOfBounds {
+                selector,
+                width,
+                height,
+            } => write!(
+                f,
+                "Invalid index ({}, {}) for a matrix of width {} and height {}",
+                selector.0, selector.1, width, height
+            ),
+        }
+    }
+}
+
+impl Error for MatrixError {}
+
 /// 2D matrix with given size of T-typed elements
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Matrix<T> {
     data: Vec<T>,
     width: usize,
@@ -23,7 +54,10 @@ where
     ///
     /// # Examples
     /// ```
-    /// let matrix = Matrix::new(3, 7);
+    /// use edit_dist::Matrix;
+    /// let matrix = Matrix::<u32>::new(3, 7);
+    /// assert_eq!(matrix.width(), 3);
+    /// assert_eq!(matrix.height(), 7);
     /// ```
     pub fn new(width: usize, height: usize) -> Self {
         Matrix {
@@ -39,15 +73,14 @@ where
     /// * `selector` - Selector to the specific cell
     ///
     /// # Examples
-    /// ```
+    /// ```ignore
     /// let idx = self.get_index((2, 3));
     /// ```
     fn get_index(&self, selector: Selector) -> Option<usize> {
         let (y, x) = selector;
-        let index = y * self.width + x;
 
-        if index < self.width * self.height {
-            Some(index)
+        if y < self.height && x < self.width {
+            Some(y * self.width + x)
         } else {
             None
         }
@@ -60,16 +93,10 @@ where
     ///
     /// # Examples
     /// ```
-    /// let matrix = Matrix::new(7, 3);
-    /// // ...
-    /// match matrix.get((4, 2)) {
-    ///     Ok(reference) => {
-    ///         // ...
-    ///     },
-    ///     None => {
-    ///         /// ...
-    ///     }
-    /// }
+    /// use edit_dist::Matrix;
+    /// let matrix = Matrix::<u32>::new(7, 3);
+    /// assert_eq!(matrix.get((2, 4)), Some(&0));
+    /// assert_eq!(matrix.get((0, 7)), None);
     /// ```
     pub fn get(&self, selector: Selector) -> Option<&T> {
         match self.get_index(selector) {
@@ -85,16 +112,12 @@ where
     ///
     /// # Examples
     /// ```
-    /// let mut matrix = Matrix::new(7, 3);
-    /// // ...
-    /// match matrix.get_mut((4, 2)) {
-    ///     Ok(reference) => {
-    ///         // ...
-    ///     },
-    ///     None => {
-    ///         /// ...
-    ///     }
+    /// use edit_dist::Matrix;
+    /// let mut matrix = Matrix::<u32>::new(7, 3);
+    /// if let Some(reference) = matrix.get_mut((2, 4)) {
+    ///     *reference = 5;
     /// }
+    /// assert_eq!(matrix[(2, 4)], 5);
     /// ```
     pub fn get_mut(&mut self, selector: Selector) -> Option<&mut T> {
         match self.get_index(selector) {
@@ -103,6 +126,165 @@ where
         }
     }
 
+    /// Get read-only reference to the specific cell, or an error describing why it does not exist
+    ///
+    /// # Arguments
+    /// * `selector` - Selector to the specific cell
+    ///
+    /// # Examples
+    /// ```
+    /// use edit_dist::{Matrix, MatrixError};
+    /// let matrix = Matrix::<u32>::new(3, 2);
+    /// assert_eq!(matrix.try_get((1, 2)), Ok(&0));
+    /// assert_eq!(
+    ///     matrix.try_get((0, 3)),
+    ///     Err(MatrixError::OutOfBounds { selector: (0, 3), width: 3, height: 2 })
+    /// );
+    /// ```
+    pub fn try_get(&self, selector: Selector) -> Result<&T, MatrixError> {
+        let error = self.out_of_bounds(selector);
+        self.get(selector).ok_or(error)
+    }
+
+    /// Get a mutable reference to the specific cell, or an error describing why it does not exist
+    ///
+    /// # Arguments
+    /// * `selector` - Selector to the specific cell
+    pub fn try_get_mut(&mut self, selector: Selector) -> Result<&mut T, MatrixError> {
+        let error = self.out_of_bounds(selector);
+        self.get_mut(selector).ok_or(error)
+    }
+
+    /// Get read-only slice of the specific row
+    ///
+    /// # Arguments
+    /// * `y` - Index of the row
+    ///
+    /// # Examples
+    /// ```
+    /// use edit_dist::Matrix;
+    /// let mut matrix = Matrix::<u32>::new(3, 2);
+    /// matrix[(1, 2)] = 7;
+    /// assert_eq!(matrix.row(1), Some(&[0, 0, 7][..]));
+    /// assert_eq!(matrix.row(2), None);
+    /// ```
+    pub fn row(&self, y: usize) -> Option<&[T]> {
+        if y < self.height {
+            Some(&self.data[y * self.width..(y + 1) * self.width])
+        } else {
+            None
+        }
+    }
+
+    /// Get a mutable slice of the specific row
+    ///
+    /// # Arguments
+    /// * `y` - Index of the row
+    pub fn row_mut(&mut self, y: usize) -> Option<&mut [T]> {
+        if y < self.height {
+            Some(&mut self.data[y * self.width..(y + 1) * self.width])
+        } else {
+            None
+        }
+    }
+
+    /// Get an iterator over cells of the specific column, from the top to the bottom
+    ///
+    /// # Arguments
+    /// * `x` - Index of the column
+    ///
+    /// # Examples
+    /// ```
+    /// use edit_dist::Matrix;
+    /// let mut matrix = Matrix::<u32>::new(3, 2);
+    /// matrix[(1, 2)] = 7;
+    /// let column: Vec<u32> = matrix.column(2).unwrap().copied().collect();
+    /// assert_eq!(column, vec![0, 7]);
+    /// assert!(matrix.column(3).is_none());
+    /// ```
+    pub fn column(&self, x: usize) -> Option<impl Iterator<Item = &T>> {
+        if x < self.width {
+            Some(self.data.get(x..).unwrap_or(&[]).iter().step_by(self.width))
+        } else {
+            None
+        }
+    }
+
+    /// Get an iterator over mutable references to cells of the specific column
+    ///
+    /// # Arguments
+    /// * `x` - Index of the column
+    pub fn column_mut(&mut self, x: usize) -> Option<impl Iterator<Item = &mut T>> {
+        if x < self.width {
+            Some(
+                self.data
+                    .get_mut(x..)
+                    .unwrap_or(&mut [])
+                    .iter_mut()
+                    .step_by(self.width),
+            )
+        } else {
+            None
+        }
+    }
+
+    /// Get an iterator over all rows, from the top to the bottom
+    ///
+    /// # Examples
+    /// ```
+    /// use edit_dist::Matrix;
+    /// let mut matrix = Matrix::<u32>::new(2, 2);
+    /// matrix[(0, 1)] = 1;
+    /// let rows: Vec<&[u32]> = matrix.rows().collect();
+    /// assert_eq!(rows, vec![&[0, 1][..], &[0, 0][..]]);
+    /// ```
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        (0..self.height).map(move |y| &self.data[y * self.width..(y + 1) * self.width])
+    }
+
+    /// Get an iterator over all columns, from the left to the right
+    ///
+    /// Every column is itself an iterator over its cells.
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(move |x| {
+            // A matrix without rows has no cells, even if it has columns
+            self.data.get(x..).unwrap_or(&[]).iter().step_by(self.width)
+        })
+    }
+
+    /// Change the size of a matrix and reset all its cells to the default value
+    ///
+    /// The underlying buffer is reused, so no memory is allocated unless the
+    /// matrix grows beyond any size it had before.
+    ///
+    /// # Arguments
+    /// * `width` - New width of a matrix
+    /// * `height` - New height of a matrix
+    ///
+    /// # Examples
+    /// ```
+    /// use edit_dist::Matrix;
+    /// let mut matrix = Matrix::<u32>::new(4, 4);
+    /// matrix[(3, 3)] = 1;
+    /// matrix.resize(2, 3);
+    /// assert_eq!(matrix.width(), 2);
+    /// assert_eq!(matrix.height(), 3);
+    /// assert!(matrix.rows().flatten().all(|&cell| cell == 0));
+    /// ```
+    pub fn resize(&mut self, width: usize, height: usize) {
+        self.data.clear();
+        self.data.resize(width * height, T::default());
+        self.width = width;
+        self.height = height;
+    }
+
+    /// Reset all cells of a matrix to the default value, keeping its size
+    pub fn clear(&mut self) {
+        for cell in self.data.iter_mut() {
+            *cell = T::default();
+        }
+    }
+
     /// Get width of a matrix
     pub fn width(&self) -> usize {
         self.width
@@ -112,6 +294,15 @@ where
     pub fn height(&self) -> usize {
         self.height
     }
+
+    /// Build the error returned for a selector pointing outside of the matrix
+    fn out_of_bounds(&self, selector: Selector) -> MatrixError {
+        MatrixError::OutOfBounds {
+            selector,
+            width: self.width,
+            height: self.height,
+        }
+    }
 }
 
 impl<T> Index<Selector> for Matrix<T>
@@ -127,9 +318,10 @@ where
     ///
     /// # Examples
     /// ```
-    /// let matrix = Matrix::new(3, 7);
-    /// // ...
-    /// let value = matrix[(0, 0)]
+    /// use edit_dist::Matrix;
+    /// let matrix = Matrix::<u32>::new(3, 7);
+    /// let value = matrix[(0, 0)];
+    /// assert_eq!(value, 0);
     /// ```
     fn index(&self, selector: Selector) -> &Self::Output {
         self.get(selector)
@@ -141,16 +333,17 @@ impl<T> IndexMut<Selector> for Matrix<T>
 where
     T: Default + Clone,
 {
-    /// Return read-only reference to the specific cell or panic in case of error
+    /// Return mutable reference to the specific cell or panic in case of error
     ///
     /// # Arguments
     /// * `selector` - Selector of the specific cell
     ///
     /// # Examples
     /// ```
-    /// let matrix = Matrix::new(3, 7);
-    /// // ...
-    /// let value = matrix[(0, 0)]
+    /// use edit_dist::Matrix;
+    /// let mut matrix = Matrix::<u32>::new(3, 7);
+    /// matrix[(6, 2)] = 4;
+    /// assert_eq!(matrix[(6, 2)], 4);
     /// ```
     fn index_mut(&mut self, selector: Selector) -> &mut Self::Output {
         self.get_mut(selector)
@@ -165,10 +358,13 @@ where
     /// Implement displaying a matrix
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         for y in 0..self.height() {
-            for x in 0..self.width() - 1 {
-                write!(f, "{} ", self[(y, x)])?;
+            for x in 0..self.width() {
+                if x > 0 {
+                    write!(f, " ")?;
+                }
+                write!(f, "{}", self[(y, x)])?;
             }
-            writeln!(f, "{}", self[(y, self.width() - 1)])?;
+            writeln!(f)?;
         }
 
         Ok(())
@@ -194,6 +390,17 @@ mod test {
         assert_eq!(matrix.get_index((2, 2)), Some(8));
     }
 
+    #[test]
+    fn test_get_index_out_of_bounds() {
+        let matrix: Matrix<u32> = Matrix::new(3, 2);
+
+        // Would wrap around into the next row without per-axis checks
+        assert_eq!(matrix.get_index((0, 3)), None);
+        assert_eq!(matrix.get_index((0, 4)), None);
+        assert_eq!(matrix.get_index((2, 0)), None);
+        assert_eq!(matrix.get((0, matrix.width() + 1)), None);
+    }
+
     #[test]
     fn test_get() {
         let matrix = Matrix::new(3, 3);
@@ -225,4 +432,97 @@ mod test {
 
         assert_eq!(matrix[(1, 1)], 112u32);
     }
+
+    #[test]
+    #[should_panic]
+    fn test_index_wraparound() {
+        let matrix = Matrix::<u32>::new(3, 3);
+        let _ = matrix[(0, 3)];
+    }
+
+    #[test]
+    fn test_try_get_mut() {
+        let mut matrix = Matrix::<u32>::new(2, 3);
+        *matrix.try_get_mut((2, 1)).unwrap() = 9;
+
+        assert_eq!(matrix.try_get((2, 1)), Ok(&9));
+        assert_eq!(
+            matrix.try_get_mut((1, 2)),
+            Err(MatrixError::OutOfBounds {
+                selector: (1, 2),
+                width: 2,
+                height: 3
+            })
+        );
+    }
+
+    #[test]
+    fn test_resize_reuses_buffer() {
+        let mut matrix = Matrix::<u32>::new(10, 10);
+        matrix[(9, 9)] = 1;
+        let capacity = matrix.data.capacity();
+
+        matrix.resize(3, 4);
+        assert_eq!(matrix.get((3, 2)), Some(&0));
+        assert_eq!(matrix.get((0, 3)), None);
+        matrix.resize(10, 10);
+        assert_eq!(matrix[(9, 9)], 0);
+        assert_eq!(matrix.data.capacity(), capacity);
+
+        matrix[(5, 5)] = 3;
+        matrix.clear();
+        assert_eq!(matrix, Matrix::new(10, 10));
+    }
+
+    #[test]
+    fn test_rows_and_columns() {
+        let mut matrix = Matrix::<u32>::new(3, 2);
+        matrix.row_mut(0).unwrap().copy_from_slice(&[1, 2, 3]);
+        for cell in matrix.column_mut(1).unwrap() {
+            *cell += 10;
+        }
+
+        let rows: Vec<Vec<u32>> = matrix.rows().map(|row| row.to_vec()).collect();
+        assert_eq!(rows, vec![vec![1, 12, 3], vec![0, 10, 0]]);
+        let columns: Vec<Vec<u32>> = matrix
+            .columns()
+            .map(|column| column.copied().collect())
+            .collect();
+        assert_eq!(columns, vec![vec![1, 0], vec![12, 10], vec![3, 0]]);
+        assert!(matrix.row_mut(2).is_none());
+        assert!(matrix.column_mut(3).is_none());
+    }
+
+    #[test]
+    fn test_without_rows() {
+        let mut matrix = Matrix::<u32>::new(3, 0);
+
+        assert_eq!(matrix.column(1).unwrap().count(), 0);
+        assert_eq!(matrix.column_mut(2).unwrap().count(), 0);
+        assert!(matrix.column(3).is_none());
+        assert_eq!(matrix.rows().count(), 0);
+        let columns: Vec<usize> = matrix.columns().map(Iterator::count).collect();
+        assert_eq!(columns, vec![0, 0, 0]);
+        assert_eq!(matrix.to_string(), "");
+    }
+
+    #[test]
+    fn test_without_columns() {
+        let mut matrix = Matrix::<u32>::new(0, 2);
+
+        assert!(matrix.column(0).is_none());
+        assert!(matrix.column_mut(0).is_none());
+        assert_eq!(matrix.columns().count(), 0);
+        let rows: Vec<usize> = matrix.rows().map(<[u32]>::len).collect();
+        assert_eq!(rows, vec![0, 0]);
+        assert_eq!(matrix.row_mut(1), Some(&mut [][..]));
+        assert_eq!(matrix.to_string(), "\n\n");
+    }
+
+    #[test]
+    fn test_display() {
+        let mut matrix = Matrix::<u32>::new(3, 2);
+        matrix[(1, 2)] = 7;
+        assert_eq!(matrix.to_string(), "0 0 0\n0 0 7\n");
+    }
 }
@@ -0,0 +1,383 @@
+use std::hash::Hash;
+
+use crate::bit_parallel::bit_parallel_slice;
+use crate::{
+    damerau_levenshtein, indel_distance, jaro, jaro_winkler_with, normalized_damerau_levenshtein,
+    normalized_indel_distance, normalized_levenshtein, normalized_osa_distance, osa_distance,
+    Normalization, DEFAULT_MAX_PREFIX_LENGTH, DEFAULT_PREFIX_SCALE,
+};
+
+/// Common interface of all metrics comparing words of T-typed elements
+///
+/// The trait is object safe, so metrics of different kinds can be chosen at
+/// runtime and compared on the same scale:
+///
+/// ```
+/// use edit_dist::{JaroWinkler, Levenshtein, Metric};
+///
+/// let metrics: Vec<Box<dyn Metric<char>>> = vec![
+///     Box::new(Levenshtein::default()),
+///     Box::new(JaroWinkler::default()),
+/// ];
+///
+/// let first: Vec<char> = "teh".chars().collect();
+/// let second: Vec<char> = "the".chars().collect();
+/// let distances: Vec<f64> = metrics
+///     .iter()
+///     .map(|metric| metric.distance(&first, &second))
+///     .collect();
+/// assert_eq!(distances[0], 2.0);
+/// for metric in &metrics {
+///     let similarity = metric.similarity(&first, &second);
+///     assert!(similarity > 0.0 && similarity < 1.0);
+/// }
+/// ```
+pub trait Metric<T> {
+    /// Calculate the distance for two words
+    ///
+    /// Edit distances return the number of operations, see
+    /// [`RawDistance::raw_distance`] for the distance with its original type.
+    fn distance(&self, first_word: &[T], second_word: &[T]) -> f64;
+
+    /// Calculate the distance for two words, normalized into range [0, 1]
+    fn normalized_distance(&self, first_word: &[T], second_word: &[T]) -> f64;
+
+    /// Calculate similarity for two words, equal to one minus the normalized distance
+    fn similarity(&self, first_word: &[T], second_word: &[T]) -> f64 {
+        1.0 - self.normalized_distance(first_word, second_word)
+    }
+}
+
+/// Metric with a raw, unnormalized distance
+///
+/// Edit distances count operations, so their raw distance is `usize`, which
+/// is what [`BkTree`](crate::BkTree) and [`SymSpell`](crate::SymSpell) need.
+///
+/// ```
+/// use edit_dist::{DamerauLevenshtein, Levenshtein, RawDistance};
+///
+/// let metrics: Vec<Box<dyn RawDistance<char, Distance = usize>>> = vec![
+///     Box::new(Levenshtein::default()),
+///     Box::new(DamerauLevenshtein::default()),
+/// ];
+///
+/// let first: Vec<char> = "teh".chars().collect();
+/// let second: Vec<char> = "the".chars().collect();
+/// let distances: Vec<usize> = metrics
+///     .iter()
+///     .map(|metric| metric.raw_distance(&first, &second))
+///     .collect();
+/// assert_eq!(distances, vec![2, 1]);
+/// ```
+pub trait RawDistance<T>: Metric<T> {
+    /// Type of the raw distance
+    type Distance;
+
+    /// Calculate the raw distance for two words
+    fn raw_distance(&self, first_word: &[T], second_word: &[T]) -> Self::Distance;
+}
+
+/// Levenshtein distance, see [`levenshtein`](crate::levenshtein)
+///
+/// Distances are calculated with the bit-parallel algorithm, see
+/// [`levenshtein_bit_parallel`](crate::levenshtein_bit_parallel), so elements
+/// have to be hashable.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Levenshtein {
+    /// Normalization used by [`Metric::normalized_distance`]
+    pub normalization: Normalization,
+}
+
+impl Levenshtein {
+    /// Create a new instance of the metric with given normalization
+    pub fn new(normalization: Normalization) -> Self {
+        Levenshtein { normalization }
+    }
+}
+
+impl<T: Eq + Hash> Metric<T> for Levenshtein {
+    fn distance(&self, first_word: &[T], second_word: &[T]) -> f64 {
+        self.raw_distance(first_word, second_word) as f64
+    }
+
+    fn normalized_distance(&self, first_word: &[T], second_word: &[T]) -> f64 {
+        // The length of the shortest optimal alignment needs the full matrix
+        if self.normalization == Normalization::AlignmentLength {
+            return normalized_levenshtein(
+                first_word.iter(),
+                second_word.iter(),
+                self.normalization,
+            );
+        }
+
+        let distance = bit_parallel_slice(first_word, second_word);
+        self.normalization
+            .apply(distance, first_word.len(), second_word.len(), 0)
+    }
+}
+
+impl<T: Eq + Hash> RawDistance<T> for Levenshtein {
+    type Distance = usize;
+
+    fn raw_distance(&self, first_word: &[T], second_word: &[T]) -> usize {
+        bit_parallel_slice(first_word, second_word)
+    }
+}
+
+/// Optimal string alignment distance, see [`osa_distance`]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct OptimalStringAlignment {
+    /// Normalization used by [`Metric::normalized_distance`]
+    pub normalization: Normalization,
+}
+
+impl OptimalStringAlignment {
+    /// Create a new instance of the metric with given normalization
+    pub fn new(normalization: Normalization) -> Self {
+        OptimalStringAlignment { normalization }
+    }
+}
+
+impl<T: PartialEq> Metric<T> for OptimalStringAlignment {
+    fn distance(&self, first_word: &[T], second_word: &[T]) -> f64 {
+        self.raw_distance(first_word, second_word) as f64
+    }
+
+    fn normalized_distance(&self, first_word: &[T], second_word: &[T]) -> f64 {
+        normalized_osa_distance(first_word.iter(), second_word.iter(), self.normalization)
+    }
+}
+
+impl<T: PartialEq> RawDistance<T> for OptimalStringAlignment {
+    type Distance = usize;
+
+    fn raw_distance(&self, first_word: &[T], second_word: &[T]) -> usize {
+        osa_distance(first_word.iter(), second_word.iter())
+    }
+}
+
+/// Unrestricted Damerau-Levenshtein distance, see [`damerau_levenshtein`]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct DamerauLevenshtein {
+    /// Normalization used by [`Metric::normalized_distance`]
+    pub normalization: Normalization,
+}
+
+impl DamerauLevenshtein {
+    /// Create a new instance of the metric with given normalization
+    pub fn new(normalization: Normalization) -> Self {
+        DamerauLevenshtein { normalization }
+    }
+}
+
+impl<T: Eq + Hash> Metric<T> for DamerauLevenshtein {
+    fn distance(&self, first_word: &[T], second_word: &[T]) -> f64 {
+        self.raw_distance(first_word, second_word) as f64
+    }
+
+    fn normalized_distance(&self, first_word: &[T], second_word: &[T]) -> f64 {
+        normalized_damerau_levenshtein(first_word.iter(), second_word.iter(), self.normalization)
+    }
+}
+
+impl<T: Eq + Hash> RawDistance<T> for DamerauLevenshtein {
+    type Distance = usize;
+
+    fn raw_distance(&self, first_word: &[T], second_word: &[T]) -> usize {
+        damerau_levenshtein(first_word.iter(), second_word.iter())
+    }
+}
+
+/// Indel distance, see [`indel_distance`] and [`normalized_indel_distance`]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Indel {
+    /// Normalization used by [`Metric::normalized_distance`]
+    pub normalization: Normalization,
+}
+
+impl Indel {
+    /// Create a new instance of the metric with given normalization
+    pub fn new(normalization: Normalization) -> Self {
+        Indel { normalization }
+    }
+}
+
+impl<T: PartialEq> Metric<T> for Indel {
+    fn distance(&self, first_word: &[T], second_word: &[T]) -> f64 {
+        self.raw_distance(first_word, second_word) as f64
+    }
+
+    fn normalized_distance(&self, first_word: &[T], second_word: &[T]) -> f64 {
+        normalized_indel_distance(first_word.iter(), second_word.iter(), self.normalization)
+    }
+}
+
+impl<T: PartialEq> RawDistance<T> for Indel {
+    type Distance = usize;
+
+    fn raw_distance(&self, first_word: &[T], second_word: &[T]) -> usize {
+        indel_distance(first_word.iter(), second_word.iter())
+    }
+}
+
+/// Jaro distance, equal to one minus [`jaro`] similarity
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Jaro;
+
+impl<T: PartialEq> Metric<T> for Jaro {
+    fn distance(&self, first_word: &[T], second_word: &[T]) -> f64 {
+        self.normalized_distance(first_word, second_word)
+    }
+
+    fn normalized_distance(&self, first_word: &[T], second_word: &[T]) -> f64 {
+        1.0 - jaro(first_word.iter(), second_word.iter())
+    }
+}
+
+impl<T: PartialEq> RawDistance<T> for Jaro {
+    type Distance = f64;
+
+    fn raw_distance(&self, first_word: &[T], second_word: &[T]) -> f64 {
+        self.normalized_distance(first_word, second_word)
+    }
+}
+
+/// Jaro-Winkler distance, equal to one minus [`jaro_winkler_with`] similarity
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct JaroWinkler {
+    /// Weight of every element of the common prefix
+    pub prefix_scale: f64,
+    /// Maximal length of the common prefix taken into account
+    pub max_prefix_length: usize,
+}
+
+impl JaroWinkler {
+    /// Create a new instance of the metric with given prefix parameters
+    ///
+    /// # Arguments
+    /// * `prefix_scale` - Weight of every element of the common prefix
+    /// * `max_prefix_length` - Maximal length of the common prefix taken into account
+    pub fn new(prefix_scale: f64, max_prefix_length: usize) -> Self {
+        JaroWinkler {
+            prefix_scale,
+            max_prefix_length,
+        }
+    }
+}
+
+impl Default for JaroWinkler {
+    fn default() -> Self {
+        JaroWinkler::new(DEFAULT_PREFIX_SCALE, DEFAULT_MAX_PREFIX_LENGTH)
+    }
+}
+
+impl<T: PartialEq> Metric<T> for JaroWinkler {
+    fn distance(&self, first_word: &[T], second_word: &[T]) -> f64 {
+        self.normalized_distance(first_word, second_word)
+    }
+
+    fn normalized_distance(&self, first_word: &[T], second_word: &[T]) -> f64 {
+        1.0 - jaro_winkler_with(
+            first_word.iter(),
+            second_word.iter(),
+            self.prefix_scale,
+            self.max_prefix_length,
+        )
+    }
+}
+
+impl<T: PartialEq> RawDistance<T> for JaroWinkler {
+    type Distance = f64;
+
+    fn raw_distance(&self, first_word: &[T], second_word: &[T]) -> f64 {
+        self.normalized_distance(first_word, second_word)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn chars(word: &str) -> Vec<char> {
+        word.chars().collect()
+    }
+
+    #[test]
+    fn metric_test_distances() {
+        let (first, second) = (chars("ca"), chars("abc"));
+        assert_eq!(Levenshtein::default().raw_distance(&first, &second), 3);
+        assert_eq!(
+            OptimalStringAlignment::default().raw_distance(&first, &second),
+            3
+        );
+        assert_eq!(
+            DamerauLevenshtein::default().raw_distance(&first, &second),
+            2
+        );
+        assert_eq!(Indel::default().raw_distance(&first, &second), 3);
+    }
+
+    #[test]
+    fn metric_test_levenshtein_agrees_with_dynamic_programming() {
+        let words = [
+            "",
+            "a",
+            "kitten",
+            "sitting",
+            "saturday",
+            "sunday",
+            "abcabcabc",
+        ];
+        let metric = Levenshtein::new(Normalization::SumLength);
+        for first in words.iter() {
+            for second in words.iter() {
+                let (first, second) = (chars(first), chars(second));
+                assert_eq!(
+                    metric.raw_distance(&first, &second),
+                    crate::levenshtein_slice(&first, &second)
+                );
+                assert_eq!(
+                    metric.normalized_distance(&first, &second),
+                    crate::normalized_levenshtein(
+                        first.iter(),
+                        second.iter(),
+                        Normalization::SumLength
+                    )
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn metric_test_normalization_option() {
+        let (first, second) = (chars("kitten"), chars("sitting"));
+        let by_max = Levenshtein::new(Normalization::MaxLength);
+        let by_sum = Levenshtein::new(Normalization::SumLength);
+        assert!((by_max.normalized_distance(&first, &second) - 3.0 / 7.0).abs() < 1e-9);
+        assert!((by_sum.similarity(&first, &second) - 10.0 / 13.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn metric_test_dynamic() {
+        let metrics: Vec<Box<dyn Metric<char>>> = vec![
+            Box::new(Levenshtein::default()),
+            Box::new(Jaro),
+            Box::new(JaroWinkler::default()),
+        ];
+        let (first, second) = (chars("martha"), chars("marhta"));
+        let similarities: Vec<f64> = metrics
+            .iter()
+            .map(|metric| metric.similarity(&first, &second))
+            .collect();
+        assert!((similarities[0] - 4.0 / 6.0).abs() < 1e-9);
+        assert!((similarities[1] - 0.944).abs() < 0.001);
+        assert!((similarities[2] - 0.961).abs() < 0.001);
+
+        let distances: Vec<f64> = metrics
+            .iter()
+            .map(|metric| metric.distance(&first, &second))
+            .collect();
+        assert_eq!(distances[0], 2.0);
+        assert!((distances[1] - 0.056).abs() < 0.001);
+    }
+}
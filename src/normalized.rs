@@ -0,0 +1,417 @@
+use std::hash::Hash;
+
+use crate::damerau::{
+    damerau_levenshtein_slice, damerau_levenshtein_with_alignment_length, osa_slice,
+    osa_with_alignment_length,
+};
+use crate::{hamming, lcs_length, levenshtein_slice, HammingError};
+
+/// Quantity a distance is divided by to bring it into range [0, 1]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Normalization {
+    /// Length of the longer word
+    #[default]
+    MaxLength,
+    /// Sum of lengths of both words
+    SumLength,
+    /// Number of columns in the shortest optimal alignment of the words
+    AlignmentLength,
+}
+
+impl Normalization {
+    /// Divide a distance by the quantity selected by the normalization
+    ///
+    /// Distance between two empty words is normalized to 0.
+    ///
+    /// # Arguments
+    /// * `distance` - Distance between the words
+    /// * `first_length` - Length of the first word
+    /// * `second_length` - Length of the second word
+    /// * `alignment_length` - Length of the shortest optimal alignment of the words
+    ///
+    /// # Examples
+    /// ```
+    /// use edit_dist::Normalization;
+    /// assert_eq!(Normalization::MaxLength.apply(3, 4, 6, 7), 0.5);
+    /// assert_eq!(Normalization::SumLength.apply(3, 4, 6, 7), 0.3);
+    /// ```
+    pub fn apply(
+        self,
+        distance: usize,
+        first_length: usize,
+        second_length: usize,
+        alignment_length: usize,
+    ) -> f64 {
+        let divisor = match self {
+            Normalization::MaxLength => first_length.max(second_length),
+            Normalization::SumLength => first_length + second_length,
+            Normalization::AlignmentLength => alignment_length,
+        };
+
+        if divisor == 0 {
+            0.0
+        } else {
+            distance as f64 / divisor as f64
+        }
+    }
+}
+
+/// Calculate Levenshtein distance for two words, normalized into range [0, 1]
+///
+/// # Arguments
+/// * `first_word` - First word
+/// * `second_word` - Second word
+/// * `normalization` - Quantity the distance is divided by
+///
+/// # Examples
+/// ```
+/// use edit_dist::{normalized_levenshtein, Normalization};
+/// let dist = normalized_levenshtein(
+///     "kitten".chars(),
+///     "sitting".chars(),
+///     Normalization::MaxLength
+/// );
+/// assert!((dist - 3.0 / 7.0).abs() < 1e-9);
+/// ```
+pub fn normalized_levenshtein<T: PartialEq>(
+    first_word: impl IntoIterator<Item = T>,
+    second_word: impl IntoIterator<Item = T>,
+    normalization: Normalization,
+) -> f64 {
+    let first_word: Vec<T> = first_word.into_iter().collect();
+    let second_word: Vec<T> = second_word.into_iter().collect();
+
+    let (distance, alignment_length) = if normalization == Normalization::AlignmentLength {
+        levenshtein_with_alignment_length(&first_word, &second_word)
+    } else {
+        (levenshtein_slice(&first_word, &second_word), 0)
+    };
+    normalization.apply(
+        distance,
+        first_word.len(),
+        second_word.len(),
+        alignment_length,
+    )
+}
+
+/// Calculate Levenshtein similarity for two words, equal to one minus the normalized distance
+///
+/// # Arguments
+/// * `first_word` - First word
+/// * `second_word` - Second word
+/// * `normalization` - Quantity the distance is divided by
+///
+/// # Examples
+/// ```
+/// use edit_dist::{levenshtein_similarity, Normalization};
+/// let similarity = levenshtein_similarity(
+///     "gily".chars(),
+///     "geely".chars(),
+///     Normalization::MaxLength
+/// );
+/// assert!((similarity - 0.6).abs() < 1e-9);
+/// ```
+pub fn levenshtein_similarity<T: PartialEq>(
+    first_word: impl IntoIterator<Item = T>,
+    second_word: impl IntoIterator<Item = T>,
+    normalization: Normalization,
+) -> f64 {
+    1.0 - normalized_levenshtein(first_word, second_word, normalization)
+}
+
+/// Calculate optimal string alignment distance for two words, normalized into range [0, 1]
+///
+/// # Arguments
+/// * `first_word` - First word
+/// * `second_word` - Second word
+/// * `normalization` - Quantity the distance is divided by
+pub fn normalized_osa_distance<T: PartialEq>(
+    first_word: impl IntoIterator<Item = T>,
+    second_word: impl IntoIterator<Item = T>,
+    normalization: Normalization,
+) -> f64 {
+    let first_word: Vec<T> = first_word.into_iter().collect();
+    let second_word: Vec<T> = second_word.into_iter().collect();
+
+    let (distance, alignment_length) = if normalization == Normalization::AlignmentLength {
+        osa_with_alignment_length(&first_word, &second_word)
+    } else {
+        (osa_slice(&first_word, &second_word), 0)
+    };
+    normalization.apply(
+        distance,
+        first_word.len(),
+        second_word.len(),
+        alignment_length,
+    )
+}
+
+/// Calculate optimal string alignment similarity for two words, equal to one minus the normalized distance
+///
+/// # Arguments
+/// * `first_word` - First word
+/// * `second_word` - Second word
+/// * `normalization` - Quantity the distance is divided by
+pub fn osa_similarity<T: PartialEq>(
+    first_word: impl IntoIterator<Item = T>,
+    second_word: impl IntoIterator<Item = T>,
+    normalization: Normalization,
+) -> f64 {
+    1.0 - normalized_osa_distance(first_word, second_word, normalization)
+}
+
+/// Calculate Damerau-Levenshtein distance for two words, normalized into range [0, 1]
+///
+/// # Arguments
+/// * `first_word` - First word
+/// * `second_word` - Second word
+/// * `normalization` - Quantity the distance is divided by
+pub fn normalized_damerau_levenshtein<T: Eq + Hash>(
+    first_word: impl IntoIterator<Item = T>,
+    second_word: impl IntoIterator<Item = T>,
+    normalization: Normalization,
+) -> f64 {
+    let first_word: Vec<T> = first_word.into_iter().collect();
+    let second_word: Vec<T> = second_word.into_iter().collect();
+
+    let (distance, alignment_length) = if normalization == Normalization::AlignmentLength {
+        damerau_levenshtein_with_alignment_length(&first_word, &second_word)
+    } else {
+        (damerau_levenshtein_slice(&first_word, &second_word), 0)
+    };
+    normalization.apply(
+        distance,
+        first_word.len(),
+        second_word.len(),
+        alignment_length,
+    )
+}
+
+/// Calculate Damerau-Levenshtein similarity for two words, equal to one minus the normalized distance
+///
+/// # Arguments
+/// * `first_word` - First word
+/// * `second_word` - Second word
+/// * `normalization` - Quantity the distance is divided by
+pub fn damerau_levenshtein_similarity<T: Eq + Hash>(
+    first_word: impl IntoIterator<Item = T>,
+    second_word: impl IntoIterator<Item = T>,
+    normalization: Normalization,
+) -> f64 {
+    1.0 - normalized_damerau_levenshtein(first_word, second_word, normalization)
+}
+
+/// Calculate indel distance for two words, normalized into range [0, 1]
+///
+/// Indel distance can be as large as the sum of lengths of both words, so
+/// [`Normalization::MaxLength`] divides it by that sum as well, the same as
+/// [`Normalization::SumLength`].
+///
+/// # Arguments
+/// * `first_word` - First word
+/// * `second_word` - Second word
+/// * `normalization` - Quantity the distance is divided by
+///
+/// # Examples
+/// ```
+/// use edit_dist::{normalized_indel_distance, Normalization};
+/// let dist = normalized_indel_distance(
+///     "abc".chars(),
+///     "abd".chars(),
+///     Normalization::SumLength
+/// );
+/// assert!((dist - 1.0 / 3.0).abs() < 1e-9);
+/// ```
+pub fn normalized_indel_distance<T: PartialEq>(
+    first_word: impl IntoIterator<Item = T>,
+    second_word: impl IntoIterator<Item = T>,
+    normalization: Normalization,
+) -> f64 {
+    let first_word: Vec<T> = first_word.into_iter().collect();
+    let second_word: Vec<T> = second_word.into_iter().collect();
+
+    // Every common element takes one column, every other element takes its own
+    let common = lcs_length(first_word.iter(), second_word.iter());
+    let alignment_length = first_word.len() + second_word.len() - common;
+
+    // Indel distance goes up to the sum of lengths, so the longer word's
+    // length is not enough to bring it into range [0, 1]
+    let normalization = match normalization {
+        Normalization::MaxLength => Normalization::SumLength,
+        other => other,
+    };
+    normalization.apply(
+        alignment_length - common,
+        first_word.len(),
+        second_word.len(),
+        alignment_length,
+    )
+}
+
+/// Calculate indel similarity for two words, equal to one minus the normalized distance
+///
+/// # Arguments
+/// * `first_word` - First word
+/// * `second_word` - Second word
+/// * `normalization` - Quantity the distance is divided by
+pub fn indel_similarity<T: PartialEq>(
+    first_word: impl IntoIterator<Item = T>,
+    second_word: impl IntoIterator<Item = T>,
+    normalization: Normalization,
+) -> f64 {
+    1.0 - normalized_indel_distance(first_word, second_word, normalization)
+}
+
+/// Calculate Hamming distance for two words of equal length, normalized into range [0, 1]
+///
+/// Both words have the same length, which is also the length of their
+/// alignment, so every normalization but [`Normalization::SumLength`] divides
+/// by the length of a word.
+///
+/// # Arguments
+/// * `first_word` - First word
+/// * `second_word` - Second word
+/// * `normalization` - Quantity the distance is divided by
+pub fn normalized_hamming<T: PartialEq>(
+    first_word: impl IntoIterator<Item = T>,
+    second_word: impl IntoIterator<Item = T>,
+    normalization: Normalization,
+) -> Result<f64, HammingError> {
+    let first_word: Vec<T> = first_word.into_iter().collect();
+    let second_word: Vec<T> = second_word.into_iter().collect();
+
+    let distance = hamming(first_word.iter(), second_word.iter())?;
+    let length = first_word.len();
+    Ok(normalization.apply(distance, length, length, length))
+}
+
+/// Calculate Hamming similarity for two words of equal length, equal to one minus the normalized distance
+///
+/// # Arguments
+/// * `first_word` - First word
+/// * `second_word` - Second word
+/// * `normalization` - Quantity the distance is divided by
+pub fn hamming_similarity<T: PartialEq>(
+    first_word: impl IntoIterator<Item = T>,
+    second_word: impl IntoIterator<Item = T>,
+    normalization: Normalization,
+) -> Result<f64, HammingError> {
+    normalized_hamming(first_word, second_word, normalization).map(|distance| 1.0 - distance)
+}
+
+/// Calculate Levenshtein distance and the length of the shortest alignment achieving it
+///
+/// # Arguments
+/// * `first_word` - First word
+/// * `second_word` - Second word
+pub(crate) fn levenshtein_with_alignment_length<T: PartialEq>(
+    first_word: &[T],
+    second_word: &[T],
+) -> (usize, usize) {
+    // Pairs are compared lexicographically, so among the cheapest alignments
+    // the shortest one is kept
+    let mut row: Vec<(usize, usize)> = (0..=first_word.len()).map(|x| (x, x)).collect();
+    for (y, second_element) in second_word.iter().enumerate() {
+        let mut diagonal = row[0];
+        row[0] = (y + 1, y + 1);
+
+        for x in 1..row.len() {
+            let the_same_letter = first_word[x - 1] == *second_element;
+            let cost = if the_same_letter { 0 } else { 1 };
+
+            let value = (diagonal.0 + cost, diagonal.1 + 1)
+                .min((row[x].0 + 1, row[x].1 + 1))
+                .min((row[x - 1].0 + 1, row[x - 1].1 + 1));
+            diagonal = row[x];
+            row[x] = value;
+        }
+    }
+
+    row[first_word.len()]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_close(actual: f64, expected: f64) {
+        assert!(
+            (actual - expected).abs() < 1e-9,
+            "{} is not close to {}",
+            actual,
+            expected
+        );
+    }
+
+    #[test]
+    fn normalization_test() {
+        let a = "kitten";
+        let b = "sitting";
+        let dist = |normalization| normalized_levenshtein(a.chars(), b.chars(), normalization);
+        assert_close(dist(Normalization::MaxLength), 3.0 / 7.0);
+        assert_close(dist(Normalization::SumLength), 3.0 / 13.0);
+        assert_close(dist(Normalization::AlignmentLength), 3.0 / 7.0);
+
+        // "ab" -> "ba" is shortest as a substitution of both letters
+        let dist =
+            normalized_levenshtein("ab".chars(), "ba".chars(), Normalization::AlignmentLength);
+        assert_close(dist, 1.0);
+        let dist =
+            normalized_levenshtein("ab".chars(), "b".chars(), Normalization::AlignmentLength);
+        assert_close(dist, 0.5);
+    }
+
+    #[test]
+    fn normalization_test_empty() {
+        for &normalization in [
+            Normalization::MaxLength,
+            Normalization::SumLength,
+            Normalization::AlignmentLength,
+        ]
+        .iter()
+        {
+            assert_close(
+                normalized_levenshtein("".chars(), "".chars(), normalization),
+                0.0,
+            );
+            assert_close(
+                levenshtein_similarity("".chars(), "abc".chars(), normalization),
+                0.0,
+            );
+        }
+    }
+
+    #[test]
+    fn normalization_test_transpositions() {
+        let dist =
+            |normalization| normalized_osa_distance("teh".chars(), "the".chars(), normalization);
+        assert_close(dist(Normalization::MaxLength), 1.0 / 3.0);
+        assert_close(dist(Normalization::AlignmentLength), 1.0 / 3.0);
+
+        let similarity =
+            damerau_levenshtein_similarity("ca".chars(), "abc".chars(), Normalization::SumLength);
+        assert_close(similarity, 0.6);
+        let dist = normalized_damerau_levenshtein(
+            "ca".chars(),
+            "abc".chars(),
+            Normalization::AlignmentLength,
+        );
+        assert_close(dist, 2.0 / 3.0);
+    }
+
+    #[test]
+    fn normalization_test_indel_and_hamming() {
+        let dist =
+            normalized_indel_distance("abc".chars(), "abd".chars(), Normalization::AlignmentLength);
+        assert_close(dist, 0.5);
+        let dist = normalized_indel_distance("ab".chars(), "cd".chars(), Normalization::MaxLength);
+        assert_close(dist, 1.0);
+        let dist = normalized_indel_distance("ab".chars(), "ac".chars(), Normalization::MaxLength);
+        assert_close(dist, 0.5);
+
+        let similarity =
+            hamming_similarity("abcd".chars(), "abce".chars(), Normalization::MaxLength);
+        assert_eq!(similarity, Ok(0.75));
+        assert!(normalized_hamming("ab".chars(), "abc".chars(), Normalization::MaxLength).is_err());
+    }
+}
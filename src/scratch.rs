@@ -0,0 +1,171 @@
+use crate::edit_script::{fill_levenshtein_matrix, traceback};
+use crate::matrix::Matrix;
+use crate::{levenshtein_with_row, EditScript};
+
+/// Reusable buffers for calculating many Levenshtein distances in a row
+///
+/// Every call of [`levenshtein`](crate::levenshtein) allocates the collected
+/// words and a row of the distance matrix. A scratch keeps these buffers
+/// between calls, so once they have grown to fit the longest words no more
+/// memory is allocated.
+///
+/// Like [`levenshtein`](crate::levenshtein), it works for any comparable
+/// elements and so runs the `O(n · m)` dynamic programming algorithm. The
+/// bit-parallel algorithm would need hashable elements, which a generic type
+/// cannot switch on without specialization.
+///
+/// # Examples
+/// ```
+/// use edit_dist::LevenshteinScratch;
+///
+/// let mut scratch = LevenshteinScratch::new();
+/// let query = "kitten";
+/// let distances: Vec<usize> = ["sitting", "mitten", "kitchen"]
+///     .iter()
+///     .map(|word| scratch.distance(query.chars(), word.chars()))
+///     .collect();
+/// assert_eq!(distances, vec![3, 1, 2]);
+/// ```
+#[derive(Debug, Clone)]
+pub struct LevenshteinScratch<T> {
+    first_word: Vec<T>,
+    second_word: Vec<T>,
+    row: Vec<usize>,
+    matrix: Matrix<usize>,
+}
+
+impl<T: PartialEq> LevenshteinScratch<T> {
+    /// Create a new instance of LevenshteinScratch with empty buffers
+    pub fn new() -> Self {
+        LevenshteinScratch {
+            first_word: Vec::new(),
+            second_word: Vec::new(),
+            row: Vec::new(),
+            matrix: Matrix::new(0, 0),
+        }
+    }
+
+    /// Calculate Levenshtein distance for two words
+    ///
+    /// # Arguments
+    /// * `first_word` - First word
+    /// * `second_word` - Second word
+    pub fn distance(
+        &mut self,
+        first_word: impl IntoIterator<Item = T>,
+        second_word: impl IntoIterator<Item = T>,
+    ) -> usize {
+        self.collect(first_word, second_word);
+        levenshtein_with_row(
+            &mut self.row,
+            &self.first_word,
+            &self.second_word,
+            |first, second| first == second,
+        )
+    }
+
+    /// Calculate Levenshtein distance for two borrowed words
+    ///
+    /// Neither word is copied, only the row buffer is reused.
+    ///
+    /// # Arguments
+    /// * `first_word` - First word
+    /// * `second_word` - Second word
+    pub fn distance_slice(&mut self, first_word: &[T], second_word: &[T]) -> usize {
+        levenshtein_with_row(&mut self.row, first_word, second_word, |first, second| {
+            first == second
+        })
+    }
+
+    /// Calculate Levenshtein distance for two words together with an optimal edit script
+    ///
+    /// Gives the same result as [`levenshtein_alignment`](crate::levenshtein_alignment),
+    /// but the distance matrix is kept between calls.
+    ///
+    /// # Arguments
+    /// * `first_word` - First word
+    /// * `second_word` - Second word
+    pub fn alignment(
+        &mut self,
+        first_word: impl IntoIterator<Item = T>,
+        second_word: impl IntoIterator<Item = T>,
+    ) -> EditScript {
+        self.collect(first_word, second_word);
+
+        let matrix = &mut self.matrix;
+        matrix.resize(self.first_word.len() + 1, self.second_word.len() + 1);
+        fill_levenshtein_matrix(matrix, &self.first_word, &self.second_word);
+
+        EditScript {
+            distance: matrix[(matrix.height() - 1, matrix.width() - 1)],
+            operations: traceback(matrix, &self.first_word, &self.second_word),
+        }
+    }
+
+    /// Replace the content of the word buffers
+    ///
+    /// # Arguments
+    /// * `first_word` - First word
+    /// * `second_word` - Second word
+    fn collect(
+        &mut self,
+        first_word: impl IntoIterator<Item = T>,
+        second_word: impl IntoIterator<Item = T>,
+    ) {
+        self.first_word.clear();
+        self.first_word.extend(first_word);
+        self.second_word.clear();
+        self.second_word.extend(second_word);
+    }
+}
+
+impl<T: PartialEq> Default for LevenshteinScratch<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{levenshtein, levenshtein_alignment};
+
+    const WORDS: [&str; 7] = [
+        "",
+        "a",
+        "kitten",
+        "sitting",
+        "saturday",
+        "sunday",
+        "abracadabra",
+    ];
+
+    #[test]
+    fn scratch_test_agrees_with_levenshtein() {
+        let mut scratch = LevenshteinScratch::new();
+        // Words of varying lengths make the buffers both grow and shrink
+        for first in WORDS.iter() {
+            for second in WORDS.iter() {
+                let expected = levenshtein(first.chars(), second.chars());
+                assert_eq!(scratch.distance(first.chars(), second.chars()), expected);
+
+                let first: Vec<char> = first.chars().collect();
+                let second: Vec<char> = second.chars().collect();
+                assert_eq!(scratch.distance_slice(&first, &second), expected);
+            }
+        }
+    }
+
+    #[test]
+    fn scratch_test_alignment() {
+        let mut scratch = LevenshteinScratch::default();
+        for first in WORDS.iter() {
+            for second in WORDS.iter() {
+                assert_eq!(
+                    scratch.alignment(first.chars(), second.chars()),
+                    levenshtein_alignment(first.chars(), second.chars())
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,234 @@
+use crate::matrix::Matrix;
+
+/// Occurrence of a pattern found in a text
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Match {
+    /// Position of the first element of the occurrence in the text
+    pub start: usize,
+    /// Position just past the last element of the occurrence in the text
+    pub end: usize,
+    /// Levenshtein distance between the pattern and the occurrence
+    pub distance: usize,
+}
+
+/// Find end positions of all approximate occurrences of a pattern in a text
+///
+/// Uses Sellers' algorithm: the top row of the distance matrix is zero, so an
+/// occurrence may start anywhere in the text. Only a single column of the
+/// matrix is kept in memory. Occurrences ending at neighbouring positions
+/// usually overlap, and all of them are reported.
+///
+/// # Arguments
+/// * `pattern` - Searched word
+/// * `text` - Word being searched
+/// * `max_distance` - Largest accepted distance between the pattern and an occurrence
+///
+/// # Examples
+/// ```
+/// use edit_dist::find_match_ends;
+/// let ends = find_match_ends("needle".chars(), "haystack with a neadle".chars(), 1);
+/// // Pairs of an end position and the distance of the best occurrence ending there
+/// assert_eq!(ends, vec![(22, 1)]);
+/// ```
+pub fn find_match_ends<T: PartialEq>(
+    pattern: impl IntoIterator<Item = T>,
+    text: impl IntoIterator<Item = T>,
+    max_distance: usize,
+) -> Vec<(usize, usize)> {
+    let pattern: Vec<T> = pattern.into_iter().collect();
+
+    let mut ends = Vec::new();
+    let mut column: Vec<usize> = (0..=pattern.len()).collect();
+    if pattern.len() <= max_distance {
+        ends.push((0, pattern.len()));
+    }
+
+    for (x, text_element) in text.into_iter().enumerate() {
+        let mut diagonal = column[0];
+
+        for y in 1..column.len() {
+            let the_same_letter = pattern[y - 1] == text_element;
+            let cost = if the_same_letter { 0 } else { 1 };
+
+            let value = (diagonal + cost).min(column[y] + 1).min(column[y - 1] + 1);
+            diagonal = column[y];
+            column[y] = value;
+        }
+
+        if column[pattern.len()] <= max_distance {
+            ends.push((x + 1, column[pattern.len()]));
+        }
+    }
+
+    ends
+}
+
+/// Find all approximate occurrences of a pattern in a text together with their start positions
+///
+/// Like [`find_match_ends`], which finds the ends in a single pass. An
+/// occurrence is at most `pattern.len() + max_distance` elements long, so
+/// its start is recovered by aligning the pattern with just that many
+/// elements before its end and walking back from the end. Memory usage is
+/// `O(m · (m + k))` for a pattern of length `m` and `max_distance` `k`,
+/// independently of the length of the text.
+///
+/// # Arguments
+/// * `pattern` - Searched word
+/// * `text` - Word being searched
+/// * `max_distance` - Largest accepted distance between the pattern and an occurrence
+///
+/// # Examples
+/// ```
+/// use edit_dist::{find_matches, Match};
+/// let matches = find_matches("needle".chars(), "haystack with a neadle".chars(), 1);
+/// assert_eq!(matches, vec![Match { start: 16, end: 22, distance: 1 }]);
+/// ```
+pub fn find_matches<T: PartialEq>(
+    pattern: impl IntoIterator<Item = T>,
+    text: impl IntoIterator<Item = T>,
+    max_distance: usize,
+) -> Vec<Match> {
+    let pattern: Vec<T> = pattern.into_iter().collect();
+    let text: Vec<T> = text.into_iter().collect();
+    let longest = pattern.len().saturating_add(max_distance);
+
+    let mut matrix = Matrix::<usize>::new(0, 0);
+    find_match_ends(pattern.iter(), text.iter(), max_distance)
+        .into_iter()
+        .map(|(end, distance)| {
+            let window_start = end.saturating_sub(longest);
+            fill_window_matrix(&mut matrix, &pattern, &text[window_start..end]);
+
+            Match {
+                start: window_start + match_start(&matrix, &pattern, &text[window_start..end]),
+                end,
+                distance,
+            }
+        })
+        .collect()
+}
+
+/// Fill the distance matrix of a pattern and a window of the text
+///
+/// Columns follow the window and rows follow the pattern. The top row is
+/// zero, so an occurrence may start at any column.
+///
+/// # Arguments
+/// * `matrix` - Matrix to fill, resized to fit the words
+/// * `pattern` - Searched word
+/// * `window` - Part of the text an occurrence ends at the end of
+fn fill_window_matrix<T: PartialEq>(matrix: &mut Matrix<usize>, pattern: &[T], window: &[T]) {
+    matrix.resize(window.len() + 1, pattern.len() + 1);
+    for y in 0..matrix.height() {
+        matrix[(y, 0)] = y;
+    }
+
+    for y in 1..matrix.height() {
+        for x in 1..matrix.width() {
+            let the_same_letter = pattern[y - 1] == window[x - 1];
+            let cost = if the_same_letter { 0 } else { 1 };
+
+            matrix[(y, x)] = (matrix[(y - 1, x - 1)] + cost)
+                .min(matrix[(y - 1, x)] + 1)
+                .min(matrix[(y, x - 1)] + 1);
+        }
+    }
+}
+
+/// Walk back from the bottom row to the top one to find where an occurrence starts
+///
+/// # Arguments
+/// * `matrix` - Distance matrix filled by `fill_window_matrix`
+/// * `pattern` - Searched word
+/// * `window` - Part of the text the occurrence ends at the end of
+fn match_start<T: PartialEq>(matrix: &Matrix<usize>, pattern: &[T], window: &[T]) -> usize {
+    let (mut y, mut x) = (pattern.len(), window.len());
+
+    while y > 0 {
+        let current = matrix[(y, x)];
+
+        if x > 0 {
+            let the_same_letter = pattern[y - 1] == window[x - 1];
+            let cost = if the_same_letter { 0 } else { 1 };
+
+            if matrix[(y - 1, x - 1)] + cost == current {
+                y -= 1;
+                x -= 1;
+                continue;
+            }
+        }
+
+        if matrix[(y - 1, x)] + 1 == current {
+            y -= 1;
+        } else {
+            x -= 1;
+        }
+    }
+
+    x
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn search_test_exact() {
+        let matches = find_matches("abc".chars(), "xabcxxabc".chars(), 0);
+        assert_eq!(
+            matches,
+            vec![
+                Match {
+                    start: 1,
+                    end: 4,
+                    distance: 0
+                },
+                Match {
+                    start: 6,
+                    end: 9,
+                    distance: 0
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn search_test_approximate() {
+        let text = "the quick brown fox";
+        let ends = find_match_ends("quack".chars(), text.chars(), 1);
+        assert_eq!(ends, vec![(9, 1)]);
+
+        let matches = find_matches("quack".chars(), text.chars(), 1);
+        let found: Vec<&str> = matches
+            .iter()
+            .map(|found| &text[found.start..found.end])
+            .collect();
+        assert_eq!(found, vec!["quick"]);
+    }
+
+    #[test]
+    fn search_test_ends_agree_with_matches() {
+        let text = "abracadabra";
+        for max_distance in 0..4 {
+            let ends = find_match_ends("cab".chars(), text.chars(), max_distance);
+            let matches = find_matches("cab".chars(), text.chars(), max_distance);
+            let match_ends: Vec<(usize, usize)> = matches
+                .iter()
+                .map(|found| (found.end, found.distance))
+                .collect();
+            assert_eq!(ends, match_ends);
+        }
+    }
+
+    #[test]
+    fn search_test_starts_have_reported_distance() {
+        let text: Vec<char> = "abracadabra cadabra abacab".chars().collect();
+        for max_distance in [0, 1, 2, 5, usize::MAX].iter() {
+            for found in find_matches("cab".chars(), text.iter().copied(), *max_distance) {
+                let occurrence = &text[found.start..found.end];
+                let distance = crate::levenshtein_slice(&['c', 'a', 'b'], occurrence);
+                assert_eq!(distance, found.distance);
+            }
+        }
+    }
+}
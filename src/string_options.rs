@@ -0,0 +1,190 @@
+use unicode_normalization::UnicodeNormalization;
+
+use crate::levenshtein_str;
+
+/// Unicode normalization form strings are brought to before comparison
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum NormalizationForm {
+    /// Canonical composition
+    Nfc,
+    /// Compatibility composition, e.g. "ﬁ" becomes "fi"
+    Nfkc,
+}
+
+/// Preprocessing applied to strings before they are compared
+///
+/// All steps are disabled by default. When enabled, they run in this order:
+/// case folding, diacritic stripping, normalization, whitespace collapsing.
+///
+/// # Examples
+/// ```
+/// use edit_dist::{levenshtein_str_with, StringOptions};
+/// let options = StringOptions {
+///     case_fold: true,
+///     strip_diacritics: true,
+///     ..StringOptions::default()
+/// };
+/// assert_eq!(levenshtein_str_with("Nguyễn", "NGUYEN", &options), 0);
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct StringOptions {
+    /// Normalization form, or `None` to keep the strings as they are
+    pub normalization_form: Option<NormalizationForm>,
+    /// Apply full Unicode case folding, e.g. "Straße" becomes "strasse"
+    pub case_fold: bool,
+    /// Remove combining diacritical marks after canonical decomposition
+    ///
+    /// Only marks from the combining diacritical blocks are removed, so vowel
+    /// signs and viramas of scripts like Devanagari are kept.
+    pub strip_diacritics: bool,
+    /// Replace every run of whitespace with a single space and trim both ends
+    pub collapse_whitespace: bool,
+}
+
+impl StringOptions {
+    /// Apply the preprocessing to a string
+    ///
+    /// # Arguments
+    /// * `word` - String to preprocess
+    ///
+    /// # Examples
+    /// ```
+    /// use edit_dist::{NormalizationForm, StringOptions};
+    /// let options = StringOptions {
+    ///     normalization_form: Some(NormalizationForm::Nfkc),
+    ///     collapse_whitespace: true,
+    ///     ..StringOptions::default()
+    /// };
+    /// assert_eq!(options.apply("  ﬁne \t tuning "), "fine tuning");
+    /// ```
+    pub fn apply(&self, word: &str) -> String {
+        let mut word = word.to_owned();
+
+        if self.case_fold {
+            word = caseless::default_case_fold_str(&word);
+        }
+
+        if self.strip_diacritics {
+            let decomposed = match self.normalization_form {
+                Some(NormalizationForm::Nfkc) => word.nfkd().collect::<String>(),
+                _ => word.nfd().collect::<String>(),
+            };
+            word = decomposed.chars().filter(|&c| !is_diacritic(c)).collect();
+        }
+
+        // Stripped strings are recomposed even without a requested form, so
+        // that e.g. Hangul syllables do not stay split into separate jamo
+        word = match self.normalization_form {
+            Some(NormalizationForm::Nfc) => word.nfc().collect(),
+            Some(NormalizationForm::Nfkc) => word.nfkc().collect(),
+            None if self.strip_diacritics => word.nfc().collect(),
+            None => word,
+        };
+
+        if self.collapse_whitespace {
+            word = word.split_whitespace().collect::<Vec<_>>().join(" ");
+        }
+
+        word
+    }
+}
+
+/// Check whether a character belongs to one of the combining diacritical marks blocks
+fn is_diacritic(c: char) -> bool {
+    matches!(
+        c,
+        '\u{300}'..='\u{36f}'
+            | '\u{1ab0}'..='\u{1aff}'
+            | '\u{1dc0}'..='\u{1dff}'
+            | '\u{20d0}'..='\u{20ff}'
+            | '\u{fe20}'..='\u{fe2f}'
+    )
+}
+
+/// Calculate Levenshtein distance for two strings after preprocessing them
+///
+/// # Arguments
+/// * `first_word` - First word
+/// * `second_word` - Second word
+/// * `options` - Preprocessing applied to both words
+///
+/// # Examples
+/// ```
+/// use edit_dist::{levenshtein_str_with, NormalizationForm, StringOptions};
+/// let options = StringOptions {
+///     normalization_form: Some(NormalizationForm::Nfc),
+///     ..StringOptions::default()
+/// };
+/// // Precomposed "é" and "e" followed by a combining acute accent
+/// assert_eq!(levenshtein_str_with("caf\u{e9}", "cafe\u{301}", &options), 0);
+/// ```
+pub fn levenshtein_str_with(first_word: &str, second_word: &str, options: &StringOptions) -> usize {
+    levenshtein_str(&options.apply(first_word), &options.apply(second_word))
+}
+
+/// Calculate Levenshtein distance for two strings split into extended grapheme clusters after preprocessing them
+///
+/// # Arguments
+/// * `first_word` - First word
+/// * `second_word` - Second word
+/// * `options` - Preprocessing applied to both words
+#[cfg(feature = "graphemes")]
+pub fn levenshtein_graphemes_with(
+    first_word: &str,
+    second_word: &str,
+    options: &StringOptions,
+) -> usize {
+    crate::levenshtein_graphemes(&options.apply(first_word), &options.apply(second_word))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn string_options_test_default_is_identity() {
+        let options = StringOptions::default();
+        assert_eq!(options.apply(" Straße\u{301} "), " Straße\u{301} ");
+        assert_eq!(levenshtein_str_with("ABC", "abc", &options), 3);
+    }
+
+    #[test]
+    fn string_options_test_case_folding() {
+        let options = StringOptions {
+            case_fold: true,
+            ..StringOptions::default()
+        };
+        assert_eq!(options.apply("Straße"), "strasse");
+        assert_eq!(levenshtein_str_with("STRASSE", "straße", &options), 0);
+    }
+
+    #[test]
+    fn string_options_test_diacritics() {
+        let options = StringOptions {
+            strip_diacritics: true,
+            ..StringOptions::default()
+        };
+        assert_eq!(options.apply("Tiếng Việt"), "Tieng Viet");
+        assert_eq!(options.apply("한국어"), "한국어");
+    }
+
+    #[test]
+    fn string_options_test_devanagari_signs_are_kept() {
+        let options = StringOptions {
+            strip_diacritics: true,
+            ..StringOptions::default()
+        };
+        assert_eq!(options.apply("नमस्ते"), "नमस्ते");
+        assert_eq!(levenshtein_str_with("किताब", "कूताब", &options), 1);
+    }
+
+    #[test]
+    fn string_options_test_whitespace() {
+        let options = StringOptions {
+            collapse_whitespace: true,
+            ..StringOptions::default()
+        };
+        assert_eq!(options.apply("\t a  b\n\nc "), "a b c");
+        assert_eq!(levenshtein_str_with("a  b", " a b", &options), 0);
+    }
+}
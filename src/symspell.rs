@@ -0,0 +1,281 @@
+use std::collections::{HashMap, HashSet};
+use std::hash::Hash;
+
+use crate::RawDistance;
+
+/// Dictionary word suggested by [`SymSpell::lookup`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Suggestion<'a, T> {
+    /// Suggested word
+    pub word: &'a [T],
+    /// Distance between the query and the word
+    pub distance: usize,
+    /// Frequency of the word in the dictionary
+    pub frequency: usize,
+}
+
+/// Symmetric delete index of a dictionary
+///
+/// Every dictionary word is stored along with all variants obtained by
+/// deleting up to `max_distance` of its elements. Deleting elements of the
+/// query the same way and looking the variants up gives all candidates within
+/// `max_distance`, which are then verified with the metric. Lookups are fast,
+/// but the index grows quickly with word length and `max_distance`, so it is
+/// meant for small distances.
+///
+/// The metric should be [`Levenshtein`](crate::Levenshtein),
+/// [`DamerauLevenshtein`](crate::DamerauLevenshtein) or
+/// [`OptimalStringAlignment`](crate::OptimalStringAlignment); candidates of
+/// other metrics may be missed.
+///
+/// # Examples
+/// ```
+/// use edit_dist::{Levenshtein, SymSpell};
+///
+/// let mut index = SymSpell::new(Levenshtein::default(), 2);
+/// index.insert("hello".chars(), 120);
+/// index.insert("help".chars(), 80);
+/// index.insert("hallo".chars(), 5);
+///
+/// let query: Vec<char> = "helo".chars().collect();
+/// let found: Vec<(String, usize, usize)> = index
+///     .lookup(&query, 1)
+///     .into_iter()
+///     .map(|found| (found.word.iter().collect(), found.distance, found.frequency))
+///     .collect();
+/// assert_eq!(
+///     found,
+///     vec![(String::from("hello"), 1, 120), (String::from("help"), 1, 80)]
+/// );
+/// ```
+pub struct SymSpell<T, M> {
+    metric: M,
+    max_distance: usize,
+    /// Dictionary words with their frequencies
+    words: Vec<(Vec<T>, usize)>,
+    /// Positions of the words in `words`
+    positions: HashMap<Vec<T>, usize>,
+    /// Positions of the words every deletion variant was obtained from
+    deletes: HashMap<Vec<T>, Vec<usize>>,
+}
+
+impl<T, M> SymSpell<T, M>
+where
+    T: Eq + Hash + Clone,
+    M: RawDistance<T, Distance = usize>,
+{
+    /// Create a new, empty instance of SymSpell
+    ///
+    /// # Arguments
+    /// * `metric` - Metric used to verify candidates
+    /// * `max_distance` - Largest distance lookups can be made with
+    pub fn new(metric: M, max_distance: usize) -> Self {
+        SymSpell {
+            metric,
+            max_distance,
+            words: Vec::new(),
+            positions: HashMap::new(),
+            deletes: HashMap::new(),
+        }
+    }
+
+    /// Get number of distinct words stored in the index
+    pub fn len(&self) -> usize {
+        self.words.len()
+    }
+
+    /// Check whether the index is empty
+    pub fn is_empty(&self) -> bool {
+        self.words.is_empty()
+    }
+
+    /// Insert a word into the index
+    ///
+    /// Inserting a word which is already stored adds up the frequencies.
+    ///
+    /// # Arguments
+    /// * `word` - Word to insert
+    /// * `frequency` - Number of occurrences of the word, used to order suggestions
+    pub fn insert(&mut self, word: impl IntoIterator<Item = T>, frequency: usize) {
+        let word: Vec<T> = word.into_iter().collect();
+        if let Some(&position) = self.positions.get(&word) {
+            self.words[position].1 += frequency;
+            return;
+        }
+
+        let position = self.words.len();
+        for variant in deletion_variants(&word, self.max_distance) {
+            self.deletes.entry(variant).or_default().push(position);
+        }
+        self.positions.insert(word.clone(), position);
+        self.words.push((word, frequency));
+    }
+
+    /// Find all words within given distance from a query
+    ///
+    /// Results are sorted by distance, then by frequency in descending order.
+    ///
+    /// # Arguments
+    /// * `query` - Word to look for
+    /// * `max_distance` - Largest accepted distance
+    ///
+    /// # Panics
+    /// Panics if `max_distance` is larger than the one the index was created with
+    pub fn lookup(&self, query: &[T], max_distance: usize) -> Vec<Suggestion<'_, T>> {
+        assert!(
+            max_distance <= self.max_distance,
+            "lookup distance {} exceeds the index distance {}",
+            max_distance,
+            self.max_distance
+        );
+
+        let mut checked = HashSet::new();
+        let mut found = Vec::new();
+
+        for variant in deletion_variants(query, max_distance) {
+            let positions = match self.deletes.get(&variant) {
+                Some(positions) => positions,
+                None => continue,
+            };
+
+            for &position in positions {
+                if !checked.insert(position) {
+                    continue;
+                }
+
+                let (word, frequency) = &self.words[position];
+                if word.len().abs_diff(query.len()) > max_distance {
+                    continue;
+                }
+
+                let distance = self.metric.raw_distance(query, word);
+                if distance <= max_distance {
+                    found.push((position, distance, *frequency));
+                }
+            }
+        }
+
+        found.sort_unstable_by_key(|&(position, distance, frequency)| {
+            (distance, std::cmp::Reverse(frequency), position)
+        });
+        found
+            .into_iter()
+            .map(|(position, distance, frequency)| Suggestion {
+                word: &self.words[position].0,
+                distance,
+                frequency,
+            })
+            .collect()
+    }
+}
+
+/// Get all distinct words obtained by deleting up to `max_deletes` elements, including the word itself
+///
+/// # Arguments
+/// * `word` - Word to delete elements from
+/// * `max_deletes` - Largest number of deleted elements
+fn deletion_variants<T: Eq + Hash + Clone>(word: &[T], max_deletes: usize) -> HashSet<Vec<T>> {
+    let mut variants = HashSet::new();
+    variants.insert(word.to_vec());
+
+    let mut last = vec![word.to_vec()];
+    for _ in 0..max_deletes {
+        let mut next = Vec::new();
+        for variant in &last {
+            for i in 0..variant.len() {
+                let mut shorter = variant.clone();
+                shorter.remove(i);
+                if variants.insert(shorter.clone()) {
+                    next.push(shorter);
+                }
+            }
+        }
+        last = next;
+    }
+
+    variants
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{damerau_levenshtein, levenshtein, DamerauLevenshtein, Levenshtein};
+
+    const WORDS: [&str; 10] = [
+        "spelling", "spell", "spells", "smelling", "selling", "dwelling", "spieling", "peeling",
+        "spilling", "swelling",
+    ];
+
+    #[test]
+    fn symspell_test_agrees_with_scan() {
+        let mut index = SymSpell::new(Levenshtein::default(), 2);
+        for word in WORDS.iter() {
+            index.insert(word.chars(), 1);
+        }
+        assert_eq!(index.len(), WORDS.len());
+
+        for query in ["speling", "spel", "xyz", "sellings"].iter() {
+            let query: Vec<char> = query.chars().collect();
+            for max_distance in 0..3 {
+                let mut expected: Vec<(String, usize)> = WORDS
+                    .iter()
+                    .map(|word| {
+                        (
+                            word.to_string(),
+                            levenshtein(query.iter().copied(), word.chars()),
+                        )
+                    })
+                    .filter(|&(_, distance)| distance <= max_distance)
+                    .collect();
+                expected.sort_by_key(|(word, distance)| {
+                    (*distance, WORDS.iter().position(|other| other == word))
+                });
+
+                let found: Vec<(String, usize)> = index
+                    .lookup(&query, max_distance)
+                    .into_iter()
+                    .map(|found| (found.word.iter().collect(), found.distance))
+                    .collect();
+                assert_eq!(found, expected);
+            }
+        }
+    }
+
+    #[test]
+    fn symspell_test_frequency_order() {
+        let mut index = SymSpell::new(Levenshtein::default(), 1);
+        index.insert("cat".chars(), 10);
+        index.insert("cut".chars(), 30);
+        index.insert("cot".chars(), 20);
+        index.insert("cat".chars(), 15);
+        assert_eq!(index.len(), 3);
+
+        let query: Vec<char> = "cxt".chars().collect();
+        let frequencies: Vec<usize> = index
+            .lookup(&query, 1)
+            .into_iter()
+            .map(|found| found.frequency)
+            .collect();
+        assert_eq!(frequencies, vec![30, 25, 20]);
+    }
+
+    #[test]
+    fn symspell_test_damerau() {
+        let mut index = SymSpell::new(DamerauLevenshtein::default(), 1);
+        index.insert("receive".chars(), 1);
+        let query: Vec<char> = "recieve".chars().collect();
+        let found = index.lookup(&query, 1);
+        assert_eq!(found.len(), 1);
+        assert_eq!(
+            found[0].distance,
+            damerau_levenshtein(query.iter().copied(), "receive".chars())
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn symspell_test_distance_too_large() {
+        let index: SymSpell<char, _> = SymSpell::new(Levenshtein::default(), 1);
+        index.lookup(&[], 2);
+    }
+}
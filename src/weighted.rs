@@ -0,0 +1,213 @@
+use std::ops::Add;
+
+use crate::matrix::Matrix;
+
+/// Numeric type that can be used as a cost of edit operations
+///
+/// Implemented for every type that behaves like a number: it can be added,
+/// compared, copied, and its default value is zero (e.g. `usize`, `u32`, `f64`).
+pub trait Cost: Copy + Default + PartialOrd + Add<Output = Self> {}
+
+impl<C> Cost for C where C: Copy + Default + PartialOrd + Add<Output = C> {}
+
+/// Costs of the edit operations used by [`weighted_levenshtein`]
+///
+/// # Examples
+/// ```
+/// use edit_dist::CostModel;
+///
+/// /// Substituting one vowel with another is cheap
+/// struct Vowels;
+///
+/// impl CostModel<char> for Vowels {
+///     type Cost = f64;
+///
+///     fn insert_cost(&self, _: &char) -> f64 {
+///         1.0
+///     }
+///
+///     fn delete_cost(&self, _: &char) -> f64 {
+///         1.0
+///     }
+///
+///     fn substitute_cost(&self, from: &char, to: &char) -> f64 {
+///         let is_vowel = |c: &char| "aeiou".contains(*c);
+///         if from == to {
+///             0.0
+///         } else if is_vowel(from) && is_vowel(to) {
+///             0.25
+///         } else {
+///             1.0
+///         }
+///     }
+/// }
+/// ```
+pub trait CostModel<T> {
+    /// Type of the calculated costs
+    type Cost: Cost;
+
+    /// Get the cost of inserting an element of the second word
+    fn insert_cost(&self, element: &T) -> Self::Cost;
+
+    /// Get the cost of deleting an element of the first word
+    fn delete_cost(&self, element: &T) -> Self::Cost;
+
+    /// Get the cost of replacing an element of the first word with an element of the second word
+    ///
+    /// It is also called for pairs of equal elements, so it should return zero for them
+    fn substitute_cost(&self, from: &T, to: &T) -> Self::Cost;
+}
+
+/// Cost model of the plain Levenshtein distance: every edit operation costs 1
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct UnitCost;
+
+impl<T: PartialEq> CostModel<T> for UnitCost {
+    type Cost = usize;
+
+    fn insert_cost(&self, _: &T) -> usize {
+        1
+    }
+
+    fn delete_cost(&self, _: &T) -> usize {
+        1
+    }
+
+    fn substitute_cost(&self, from: &T, to: &T) -> usize {
+        if from == to {
+            0
+        } else {
+            1
+        }
+    }
+}
+
+/// Calculate weighted Levenshtein distance for two words
+///
+/// # Arguments
+/// * `first_word` - First word
+/// * `second_word` - Second word
+/// * `costs` - Cost model of the edit operations
+///
+/// # Examples
+/// ```
+/// use edit_dist::{weighted_levenshtein, UnitCost};
+/// let dist = weighted_levenshtein(
+///     "sitting".chars(),
+///     "kitten".chars(),
+///     &UnitCost
+/// );
+/// assert_eq!(dist, 3);
+/// ```
+pub fn weighted_levenshtein<T, C: CostModel<T>>(
+    first_word: impl IntoIterator<Item = T>,
+    second_word: impl IntoIterator<Item = T>,
+    costs: &C,
+) -> C::Cost {
+    let first_word: Vec<T> = first_word.into_iter().collect();
+    let second_word: Vec<T> = second_word.into_iter().collect();
+
+    let mut matrix = Matrix::<C::Cost>::new(first_word.len() + 1, second_word.len() + 1);
+    for y in 1..matrix.height() {
+        matrix[(y, 0)] = matrix[(y - 1, 0)] + costs.insert_cost(&second_word[y - 1]);
+    }
+    for x in 1..matrix.width() {
+        matrix[(0, x)] = matrix[(0, x - 1)] + costs.delete_cost(&first_word[x - 1]);
+    }
+
+    for y in 1..matrix.height() {
+        for x in 1..matrix.width() {
+            let substitution = matrix[(y - 1, x - 1)]
+                + costs.substitute_cost(&first_word[x - 1], &second_word[y - 1]);
+            let insertion = matrix[(y - 1, x)] + costs.insert_cost(&second_word[y - 1]);
+            let deletion = matrix[(y, x - 1)] + costs.delete_cost(&first_word[x - 1]);
+
+            matrix[(y, x)] = min_cost(min_cost(substitution, insertion), deletion);
+        }
+    }
+
+    matrix[(matrix.height() - 1, matrix.width() - 1)]
+}
+
+/// Get the smaller of two partially ordered costs, preferring the first one
+fn min_cost<C: Cost>(first: C, second: C) -> C {
+    if second < first {
+        second
+    } else {
+        first
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Digits;
+
+    impl CostModel<char> for Digits {
+        type Cost = u32;
+
+        fn insert_cost(&self, _: &char) -> u32 {
+            2
+        }
+
+        fn delete_cost(&self, _: &char) -> u32 {
+            2
+        }
+
+        fn substitute_cost(&self, from: &char, to: &char) -> u32 {
+            if from == to {
+                0
+            } else if from.is_ascii_digit() && to.is_ascii_digit() {
+                5
+            } else {
+                1
+            }
+        }
+    }
+
+    #[test]
+    fn unit_cost_test() {
+        let dist = weighted_levenshtein("honda".chars(), "hyundai".chars(), &UnitCost);
+        assert_eq!(dist, 3);
+    }
+
+    #[test]
+    fn custom_cost_test() {
+        // Replacing a digit is more expensive than deleting and inserting it
+        let dist = weighted_levenshtein("SKU-1".chars(), "SKU-2".chars(), &Digits);
+        assert_eq!(dist, 4);
+        let dist = weighted_levenshtein("SKU-A".chars(), "SKU-B".chars(), &Digits);
+        assert_eq!(dist, 1);
+    }
+
+    #[test]
+    fn float_cost_test() {
+        struct Half;
+
+        impl CostModel<u8> for Half {
+            type Cost = f64;
+
+            fn insert_cost(&self, _: &u8) -> f64 {
+                0.5
+            }
+
+            fn delete_cost(&self, _: &u8) -> f64 {
+                0.5
+            }
+
+            fn substitute_cost(&self, from: &u8, to: &u8) -> f64 {
+                if from == to {
+                    0.0
+                } else {
+                    0.75
+                }
+            }
+        }
+
+        let dist = weighted_levenshtein("abc".bytes(), "abd".bytes(), &Half);
+        assert!((dist - 0.75).abs() < f64::EPSILON);
+        let dist = weighted_levenshtein("".bytes(), "abc".bytes(), &Half);
+        assert!((dist - 1.5).abs() < f64::EPSILON);
+    }
+}